@@ -1,5 +1,8 @@
 use crate::msrvs::Msrv;
-use crate::types::{DisallowedPath, MacroMatcher, MatchLintBehaviour, PubUnderscoreFieldsBehaviour, Rename};
+use crate::types::{
+    DisallowedPath, LibraryCrateType, MacroMatcher, MatchLintBehaviour, PubUnderscoreFieldsBehaviour, Rename,
+    StructuredErrorMessageStyle, StructuredErrorVisibility, UnstructuredErrorCategory,
+};
 use crate::ClippyConfiguration;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_session::Session;
@@ -37,6 +40,11 @@ const DEFAULT_DOC_VALID_IDENTS: &[&str] = &[
 ];
 const DEFAULT_DISALLOWED_NAMES: &[&str] = &["foo", "baz", "quux"];
 const DEFAULT_ALLOWED_IDENTS_BELOW_MIN_CHARS: &[&str] = &["i", "j", "x", "y", "z", "w", "n"];
+const DEFAULT_LIBRARY_CRATE_TYPES: &[LibraryCrateType] = &[
+    LibraryCrateType::Rlib,
+    LibraryCrateType::Dylib,
+    LibraryCrateType::Cdylib,
+];
 
 /// Conf with parse errors
 #[derive(Default)]
@@ -556,6 +564,106 @@ define_Conf! {
     /// Lint "public" fields in a struct that are prefixed with an underscore based on their
     /// exported visibility, or whether they are marked as "pub".
     (pub_underscore_fields_behavior: PubUnderscoreFieldsBehaviour = PubUnderscoreFieldsBehaviour::PubliclyExported),
+    /// Lint: LIBRARY_CRATES_STRUCTURED_ERRORS.
+    ///
+    /// A list of paths to unstructured error types that should not be linted, e.g. `"anyhow::Error"`.
+    /// Paths that don't resolve to a type, for example because of a typo, are silently ignored.
+    (allowed_unstructured_error_types: Vec<String> = Vec::new()),
+    /// Lint: LIBRARY_CRATES_STRUCTURED_ERRORS.
+    ///
+    /// A list of paths to the result types of frameworks, e.g. `"actix_web::Result"`. Functions
+    /// returning one of them aren't linted, as their signature is dictated by the framework. The
+    /// paths can refer to type aliases as well as types.
+    (framework_result_types: Vec<String> = Vec::new()),
+    /// Lint: LIBRARY_CRATES_STRUCTURED_ERRORS.
+    ///
+    /// Whether to additionally emit a summary of the unstructured error types used in the crate,
+    /// counting the functions that use each kind of error type.
+    (summarize_unstructured_errors: bool = false),
+    /// Lint: LIBRARY_CRATES_STRUCTURED_ERRORS, LIBRARY_PANICS.
+    ///
+    /// The crate types that are considered libraries, i.e. the crates that are linted. Out of the
+    /// remaining types, `staticlib` and `proc-macro` crates don't export Rust functions other
+    /// crates can call, so most users will want to keep them excluded.
+    (library_error_check_crate_types: Vec<LibraryCrateType> = DEFAULT_LIBRARY_CRATE_TYPES.to_vec()),
+    /// Lint: LIBRARY_CRATES_STRUCTURED_ERRORS, LIBRARY_PANICS.
+    ///
+    /// Whether to lint binary crates as well, e.g. if the binary is split into modules that are used
+    /// like a library. The `main` function is never linted, and neither are tests, benchmarks and
+    /// examples.
+    (enforce_structured_errors_in_binaries: bool = false),
+    /// Lint: LIBRARY_CRATES_STRUCTURED_ERRORS.
+    ///
+    /// Whether to report the methods of an `impl` block that use the same unstructured error type
+    /// with a single warning on the `impl` block, instead of one warning per method.
+    (collapse_per_impl: bool = false),
+    /// Lint: LIBRARY_CRATES_STRUCTURED_ERRORS.
+    ///
+    /// Whether to lint integer error types like `i32`, and tuples of them like `(u32, u32)`, which are
+    /// often raw error codes. This is off by default, as wrappers of C libraries commonly pass on the
+    /// codes of the wrapped library.
+    (flag_integer_error_types: bool = false),
+    /// Lint: LIBRARY_CRATES_STRUCTURED_ERRORS.
+    ///
+    /// Whether to lint error types defined in dependencies of the crate, like `serde_json::Error`.
+    /// Exposing them makes the dependency part of the public API. This is off by default, as it also
+    /// reports structured error types of dependencies.
+    (flag_foreign_error_types: bool = false),
+    /// Lint: LIBRARY_CRATES_STRUCTURED_ERRORS.
+    ///
+    /// The unstructured error types that are accepted, out of `string`, `boxed` (`Box<dyn Error>`),
+    /// `anyhow` and `eyre`, e.g. `["boxed"]` to accept boxed errors while still reporting the others.
+    (structured_error_exempt_categories: Vec<UnstructuredErrorCategory> = Vec::new()),
+    /// Lint: LIBRARY_CRATES_STRUCTURED_ERRORS, LIBRARY_STRING_ERRORS, LIBRARY_BOXED_ERRORS, LIBRARY_ERROR_PARAMETERS, ERROR_FROM_STRING, STRINGIFIED_ERROR_HANDLING, OPAQUE_ERROR_PROPAGATION, ERROR_ENUM_STRINGLY_CATCHALL.
+    ///
+    /// Whether advice on how to structure the error type that isn't a code suggestion, like "mark the
+    /// error enum `#[non_exhaustive]`", is emitted as a `note` or as a `help` message. Tools
+    /// consuming the JSON output may render the two differently.
+    (structured_error_message_style: StructuredErrorMessageStyle = StructuredErrorMessageStyle::Note),
+    /// Lint: LIBRARY_CRATES_STRUCTURED_ERRORS.
+    ///
+    /// The minimum visibility of the functions and types that are checked: `public` for the exported
+    /// API only, `crate` to also check items that are visible in the whole crate, like `pub(crate)`
+    /// functions, or `all` to check every item.
+    (structured_error_visibility: StructuredErrorVisibility = StructuredErrorVisibility::Public),
+    /// Lint: LIBRARY_CRATES_STRUCTURED_ERRORS.
+    ///
+    /// Whether to lint type parameters without any bounds that are used as the error type, like `E`
+    /// in `fn parse<E>() -> Result<(), E>`. Callers have to pick the error type, and the function
+    /// can't tell them anything about its errors.
+    (flag_unbounded_generic_errors: bool = false),
+    /// Lint: LIBRARY_CRATES_STRUCTURED_ERRORS, LIBRARY_PANICS.
+    ///
+    /// Whether to lint items marked `#[doc(hidden)]`, or nested in such an item. They aren't part of
+    /// the documented API, and are usually only exported for the expansions of the crate's macros.
+    (lint_doc_hidden: bool = false),
+    /// Lint: LIBRARY_CRATES_STRUCTURED_ERRORS.
+    ///
+    /// Whether to lint error types of the crate that only wrap an opaque error type, like
+    /// `pub struct Error(#[from] anyhow::Error)`. They look structured, but callers still can't match
+    /// on the errors.
+    (flag_opaque_wrapper_errors: bool = false),
+    /// Lint: LIBRARY_CRATES_STRUCTURED_ERRORS.
+    ///
+    /// Whether to emit a single diagnostic with the message `structured-error-violations: N` once
+    /// the crate has been checked, where `N` is the number of unstructured error types that were
+    /// reported. It's meant for CI jobs that compare the count against a threshold, e.g. with
+    /// `--message-format=json`. Nothing is emitted if there are no violations.
+    (emit_structured_error_count: bool = false),
+    /// Lint: LIBRARY_CRATES_STRUCTURED_ERRORS, LIBRARY_PANICS.
+    ///
+    /// Glob patterns for the names of the crates to lint, e.g. `mylib-*` to skip the internal
+    /// helper crates of a workspace. `*` matches any number of characters and `?` a single one. Dashes
+    /// and underscores are treated alike, as Cargo replaces the dashes of package names in crate names.
+    /// If empty, all crates are linted.
+    (structured_error_crate_globs: Vec<String> = Vec::new()),
+    /// Lint: LIBRARY_CRATES_STRUCTURED_ERRORS.
+    ///
+    /// Whether to report once the crate has been checked if its exported functions use more than one
+    /// opaque error type, e.g. `anyhow::Error` in some and `Box<dyn Error>` in others. Callers of such
+    /// an API have to handle each of them differently, and a single structured error type would
+    /// replace all of them.
+    (flag_inconsistent_error_types: bool = false),
 }
 
 /// Search for the configuration file.
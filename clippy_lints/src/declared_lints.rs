@@ -253,7 +253,17 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::let_underscore::LET_UNDERSCORE_MUST_USE_INFO,
     crate::let_underscore::LET_UNDERSCORE_UNTYPED_INFO,
     crate::let_with_type_underscore::LET_WITH_TYPE_UNDERSCORE_INFO,
+    crate::library_crates_structured_errors::ERROR_ENUM_STRINGLY_CATCHALL_INFO,
+    crate::library_crates_structured_errors::ERROR_FROM_STRING_INFO,
+    crate::library_crates_structured_errors::ERROR_TYPE_MISSING_ERROR_IMPL_INFO,
+    crate::library_crates_structured_errors::KNOWN_UNSTRUCTURED_ERRORS_INFO,
+    crate::library_crates_structured_errors::LIBRARY_BOXED_ERRORS_INFO,
     crate::library_crates_structured_errors::LIBRARY_CRATES_STRUCTURED_ERRORS_INFO,
+    crate::library_crates_structured_errors::LIBRARY_ERROR_PARAMETERS_INFO,
+    crate::library_crates_structured_errors::LIBRARY_STRING_ERRORS_INFO,
+    crate::library_crates_structured_errors::OPAQUE_ERROR_PROPAGATION_INFO,
+    crate::library_crates_structured_errors::STRINGIFIED_ERROR_HANDLING_INFO,
+    crate::library_panics::LIBRARY_PANICS_INFO,
     crate::lifetimes::EXTRA_UNUSED_LIFETIMES_INFO,
     crate::lifetimes::NEEDLESS_LIFETIMES_INFO,
     crate::lines_filter_map_ok::LINES_FILTER_MAP_OK_INFO,
@@ -286,6 +296,7 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::loops::WHILE_LET_ON_ITERATOR_INFO,
     crate::macro_use::MACRO_USE_IMPORTS_INFO,
     crate::main_recursion::MAIN_RECURSION_INFO,
+    crate::main_uses_boxed_error::MAIN_USES_BOXED_ERROR_INFO,
     crate::manual_assert::MANUAL_ASSERT_INFO,
     crate::manual_async_fn::MANUAL_ASYNC_FN_INFO,
     crate::manual_bits::MANUAL_BITS_INFO,
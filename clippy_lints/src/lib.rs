@@ -182,12 +182,14 @@ mod let_if_seq;
 mod let_underscore;
 mod let_with_type_underscore;
 mod library_crates_structured_errors;
+mod library_panics;
 mod lifetimes;
 mod lines_filter_map_ok;
 mod literal_representation;
 mod loops;
 mod macro_use;
 mod main_recursion;
+mod main_uses_boxed_error;
 mod manual_assert;
 mod manual_async_fn;
 mod manual_bits;
@@ -387,6 +389,7 @@ pub fn register_pre_expansion_lints(store: &mut rustc_lint::LintStore, conf: &'s
 #[derive(Default)]
 struct RegistrationGroups {
     all: Vec<LintId>,
+    api_hygiene: Vec<LintId>,
     cargo: Vec<LintId>,
     complexity: Vec<LintId>,
     correctness: Vec<LintId>,
@@ -404,6 +407,7 @@ impl RegistrationGroups {
     #[rustfmt::skip]
     fn register(self, store: &mut rustc_lint::LintStore) {
         store.register_group(true, "clippy::all", Some("clippy_all"), self.all);
+        store.register_group(true, "clippy::api_hygiene", Some("clippy_api_hygiene"), self.api_hygiene);
         store.register_group(true, "clippy::cargo", Some("clippy_cargo"), self.cargo);
         store.register_group(true, "clippy::complexity", Some("clippy_complexity"), self.complexity);
         store.register_group(true, "clippy::correctness", Some("clippy_correctness"), self.correctness);
@@ -457,6 +461,21 @@ impl LintCategory {
     }
 }
 
+/// The lints of the `clippy::api_hygiene` group, which checks the exported API of library crates.
+/// Unlike the categories, the group isn't exclusive, all of its lints are also in their category.
+static API_HYGIENE: &[&&Lint] = &[
+    &library_crates_structured_errors::ERROR_ENUM_STRINGLY_CATCHALL,
+    &library_crates_structured_errors::ERROR_FROM_STRING,
+    &library_crates_structured_errors::ERROR_TYPE_MISSING_ERROR_IMPL,
+    &library_crates_structured_errors::LIBRARY_BOXED_ERRORS,
+    &library_crates_structured_errors::LIBRARY_CRATES_STRUCTURED_ERRORS,
+    &library_crates_structured_errors::LIBRARY_ERROR_PARAMETERS,
+    &library_crates_structured_errors::LIBRARY_STRING_ERRORS,
+    &library_crates_structured_errors::OPAQUE_ERROR_PROPAGATION,
+    &library_crates_structured_errors::STRINGIFIED_ERROR_HANDLING,
+    &library_panics::LIBRARY_PANICS,
+];
+
 pub(crate) struct LintInfo {
     /// Double reference to maintain pointer equality
     lint: &'static &'static Lint,
@@ -495,6 +514,7 @@ fn register_categories(store: &mut rustc_lint::LintStore) {
 
         category.group(&mut groups).push(LintId::of(lint));
     }
+    groups.api_hygiene = API_HYGIENE.iter().map(|lint| LintId::of(lint)).collect();
 
     let lints: Vec<&'static Lint> = declared_lints::LINTS.iter().map(|info| *info.lint).collect();
 
@@ -576,6 +596,23 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         check_private_items,
         pub_underscore_fields_behavior,
         ref allowed_duplicate_crates,
+        ref allowed_unstructured_error_types,
+        ref framework_result_types,
+        summarize_unstructured_errors,
+        ref library_error_check_crate_types,
+        enforce_structured_errors_in_binaries,
+        collapse_per_impl,
+        flag_integer_error_types,
+        flag_foreign_error_types,
+        ref structured_error_exempt_categories,
+        structured_error_message_style,
+        structured_error_visibility,
+        flag_unbounded_generic_errors,
+        lint_doc_hidden,
+        flag_opaque_wrapper_errors,
+        emit_structured_error_count,
+        ref structured_error_crate_globs,
+        flag_inconsistent_error_types,
 
         blacklisted_names: _,
         cyclomatic_complexity_threshold: _,
@@ -1095,8 +1132,33 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(move |_| {
         Box::new(thread_local_initializer_can_be_made_const::ThreadLocalInitializerCanBeMadeConst::new(msrv()))
     });
-    store
-        .register_late_pass(move |_| Box::<library_crates_structured_errors::LibraryCratesStructuredErrors>::default());
+    let structured_errors_conf = library_crates_structured_errors::StructuredErrorsConf {
+        allowed_error_types: allowed_unstructured_error_types.clone(),
+        framework_result_types: framework_result_types.clone(),
+        summarize: summarize_unstructured_errors,
+        check_crate_types: library_error_check_crate_types.clone(),
+        check_binaries: enforce_structured_errors_in_binaries,
+        collapse_per_impl,
+        flag_integer_error_types,
+        flag_foreign_error_types,
+        exempt_categories: structured_error_exempt_categories.clone(),
+        message_style: structured_error_message_style,
+        visibility: structured_error_visibility,
+        flag_unbounded_generic_errors,
+        lint_doc_hidden,
+        flag_opaque_wrapper_errors,
+        emit_count: emit_structured_error_count,
+        crate_globs: structured_error_crate_globs.clone(),
+        flag_inconsistent: flag_inconsistent_error_types,
+    };
+    let library_panics_conf = structured_errors_conf.clone();
+    store.register_late_pass(move |_| {
+        Box::new(library_crates_structured_errors::LibraryCratesStructuredErrors::new(
+            structured_errors_conf.clone(),
+        ))
+    });
+    store.register_late_pass(|_| Box::<main_uses_boxed_error::MainUsesBoxedError>::default());
+    store.register_late_pass(move |_| Box::new(library_panics::LibraryPanics::new(&library_panics_conf)));
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
 
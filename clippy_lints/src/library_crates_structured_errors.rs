@@ -1,28 +1,102 @@
-use clippy_utils::diagnostics::span_lint_and_note;
-use clippy_utils::match_def_path;
-use clippy_utils::ty::{is_type_lang_item, result_err_ty};
-use rustc_hir::intravisit::FnKind;
-use rustc_hir::{Body, FnDecl, LangItem};
-use rustc_lint::{LateContext, LateLintPass};
-use rustc_middle::ty;
-use rustc_middle::ty::{ExistentialPredicate, Ty};
+use clippy_config::types::{
+    LibraryCrateType, StructuredErrorMessageStyle, StructuredErrorVisibility, UnstructuredErrorCategory,
+};
+use clippy_utils::attrs::{get_attr, is_doc_hidden};
+use clippy_utils::diagnostics::{span_lint, span_lint_and_then, span_lint_hir_and_then};
+use clippy_utils::macros::root_macro_call_first_node;
+use clippy_utils::source::{snippet, snippet_indent};
+use clippy_utils::str_utils::to_camel_case;
+use clippy_utils::ty::{
+    defining_crate_name, implements_error_trait, implements_trait, is_boxed_dyn_any, is_boxed_dyn_error,
+    is_string_like_ty, is_type_diagnostic_item, is_type_lang_item, is_unstructured_error_ty,
+    make_normalized_projection, peel_to_result_err, result_err_hir_ty, return_error_ty_through_body,
+};
+use clippy_utils::visitors::for_each_expr_with_closures;
+use clippy_utils::{
+    def_path_def_ids, get_parent_expr, is_entrypoint_fn, is_in_cfg_test, is_in_test_function, is_lint_allowed,
+    is_no_std_crate, is_trait_impl_item, match_def_path, paths, peel_blocks,
+};
+use core::ops::ControlFlow;
+use itertools::Itertools;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexMap};
+use rustc_errors::{Applicability, Diagnostic, SubdiagnosticMessage};
+use rustc_hir as hir;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::intravisit::{walk_poly_trait_ref, walk_ty, FnKind, Visitor};
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, FnRetTy, HirId, LangItem, MatchSource};
+use rustc_hir_analysis::hir_ty_to_ty;
+use rustc_lint::{LateContext, LateLintPass, Level, Lint, LintContext};
+use rustc_middle::hir::nested_filter;
+use rustc_middle::lint::LintLevelSource;
+use rustc_middle::ty::{self, GenericArg, Ty, TypeVisitableExt};
 use rustc_session::config::CrateType;
+use rustc_session::cstore::ExternCrate;
 use rustc_session::impl_lint_pass;
-use rustc_span::def_id::LocalDefId;
-use rustc_span::{sym, Span};
+use rustc_span::def_id::{DefId, LocalDefId, CRATE_DEF_ID, LOCAL_CRATE};
+use rustc_span::hygiene::{ExpnKind, MacroKind};
+use rustc_span::symbol::Ident;
+use rustc_span::{sym, FileName, Span, Symbol, DUMMY_SP};
+use rustc_target::spec::abi::Abi;
+use std::cell::OnceCell;
+use std::env;
+use std::ffi::OsStr;
+use std::path::{Component, Path, PathBuf};
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Finds usages of unstructured error types in library crates.
+    /// Finds usages of unstructured error types in the exported functions of library crates, in
+    /// the associated types of their exported traits, and in the `TryFrom` and `TryInto` impls of
+    /// their exported types. The `Output` types of trait impls, e.g. of `Index` or `Future`, are
+    /// checked for a `Result` with an unstructured error type. Public fields of exported structs
+    /// and enums are checked for opaque error types, e.g. `pub last_error: Option<anyhow::Error>`.
+    ///
+    /// This lint and the other lints for the exported API of library crates, like
+    /// `library_string_errors` and `library_panics`, can be enabled together with the
+    /// `clippy::api_hygiene` group.
+    ///
+    /// Each diagnostic has a note of the form `category: <name>`, where the name is one of
+    /// `string`, `boxed`, `anyhow`, `eyre`, `integer`, `foreign`, `unbounded-generic` or `other`.
+    /// Tools consuming the JSON output (`--message-format=json`) can use it to tell the findings
+    /// apart without parsing the message.
     ///
     /// ### Why is this bad?
     /// Libraries should use structured error types to allow users to
     /// match on different error cases.
     ///
+    /// An application is the final consumer of its own errors: it reports them to the user, logs
+    /// them or exits, and types like `anyhow::Error` make that easy by collecting any error along
+    /// with its context. That's why the lint is quiet in binary crates by default.
+    ///
+    /// The errors of a library are consumed by code its authors don't know. A caller might want to
+    /// retry after a timeout but not after invalid input, or turn a missing file into a default
+    /// value. With an `anyhow::Error`, a `Box<dyn Error>` or a `String`, the only way to do that is
+    /// to downcast to a type that isn't part of the signature, or to parse the message, and both
+    /// break silently when the library changes. An error enum makes the cases part of the API, so
+    /// callers can match on them, and changing a case is a visible change of the signature.
+    ///
+    /// `cargo clippy --explain library_crates_structured_errors` prints this explanation along
+    /// with the lint's configuration.
+    ///
     /// ### Known problems
     /// This only detects certain kinds of unstructured error types,
     /// not all of them.
     ///
+    /// Functions that intentionally expose an unstructured error type, e.g. in a plugin
+    /// framework, can be marked with `#[clippy::accepted_unstructured_error]`. Unlike
+    /// `#[allow(clippy::library_crates_structured_errors)]`, which merely silences the lint,
+    /// the attribute documents that the error type is part of the intended API. An `allow` on a
+    /// type alias of the crate, like `type MyResult<T> = anyhow::Result<T>`, applies to all the
+    /// functions using the alias.
+    ///
+    /// Functions that are known to need a structured error type, but haven't been migrated yet,
+    /// can be marked with `#[clippy::structured_error_todo]`. Their error types are reported by
+    /// the allow-by-default `known_unstructured_errors` lint instead.
+    ///
+    /// Error types that are structured, but that the heuristics of the opt-in checks disagree
+    /// with, e.g. a wrapper around an `anyhow::Error` that callers can downcast through, can be
+    /// marked with `#[clippy::structured_error_ok]`. All the structured error lints accept the
+    /// marked type, wherever it's used.
+    ///
     /// ### Example
     /// Before:
     /// ```no_run
@@ -39,96 +113,3169 @@ declare_clippy_lint! {
     ///   todo!()
     /// }
     /// ```
+    ///
+    /// An error enum with a variant per failure, which keeps the underlying errors as sources:
+    /// ```no_run
+    /// use std::{fmt, io, num};
+    ///
+    /// #[derive(Debug)]
+    /// #[non_exhaustive]
+    /// pub enum ConfigError {
+    ///     Read(io::Error),
+    ///     InvalidPort(num::ParseIntError),
+    ///     MissingKey(&'static str),
+    /// }
+    ///
+    /// impl fmt::Display for ConfigError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         match self {
+    ///             Self::Read(_) => f.write_str("failed to read the config file"),
+    ///             Self::InvalidPort(_) => f.write_str("invalid port"),
+    ///             Self::MissingKey(key) => write!(f, "missing key `{key}`"),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// impl std::error::Error for ConfigError {
+    ///     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    ///         match self {
+    ///             Self::Read(e) => Some(e),
+    ///             Self::InvalidPort(e) => Some(e),
+    ///             Self::MissingKey(_) => None,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// pub fn load_port(path: &str) -> Result<u16, ConfigError> {
+    ///     let config = std::fs::read_to_string(path).map_err(ConfigError::Read)?;
+    ///     let port = config.lines().find_map(|line| line.strip_prefix("port="));
+    ///     port.ok_or(ConfigError::MissingKey("port"))?
+    ///         .parse()
+    ///         .map_err(ConfigError::InvalidPort)
+    /// }
+    /// ```
+    ///
+    /// Callers can then handle the cases they care about, e.g. fall back to a default port:
+    /// ```ignore
+    /// let port = match load_port("app.conf") {
+    ///     Err(ConfigError::MissingKey(_)) => 8080,
+    ///     result => result?,
+    /// };
+    /// ```
     #[clippy::version = "1.77.0"]
     pub LIBRARY_CRATES_STRUCTURED_ERRORS,
     restriction,
     "library crates that use unstructured error types"
 }
 
-#[derive(Default)]
-pub struct LibraryCratesStructuredErrors {
-    is_library_crate: Option<bool>,
+/// The configuration of the lints for the exported API of library crates, built once from
+/// `clippy_config::Conf` when the lints are registered.
+#[derive(Clone)]
+#[expect(clippy::struct_excessive_bools)]
+pub struct StructuredErrorsConf {
+    /// `allowed-unstructured-error-types`
+    pub allowed_error_types: Vec<String>,
+    /// `framework-result-types`
+    pub framework_result_types: Vec<String>,
+    /// `summarize-unstructured-errors`
+    pub summarize: bool,
+    /// `library-error-check-crate-types`
+    pub check_crate_types: Vec<LibraryCrateType>,
+    /// `enforce-structured-errors-in-binaries`
+    pub check_binaries: bool,
+    /// `collapse-per-impl`
+    pub collapse_per_impl: bool,
+    /// `flag-integer-error-types`
+    pub flag_integer_error_types: bool,
+    /// `flag-foreign-error-types`
+    pub flag_foreign_error_types: bool,
+    /// `structured-error-exempt-categories`
+    pub exempt_categories: Vec<UnstructuredErrorCategory>,
+    /// `structured-error-message-style`
+    pub message_style: StructuredErrorMessageStyle,
+    /// `structured-error-visibility`
+    pub visibility: StructuredErrorVisibility,
+    /// `flag-unbounded-generic-errors`
+    pub flag_unbounded_generic_errors: bool,
+    /// `lint-doc-hidden`
+    pub lint_doc_hidden: bool,
+    /// `flag-opaque-wrapper-errors`
+    pub flag_opaque_wrapper_errors: bool,
+    /// `emit-structured-error-count`
+    pub emit_count: bool,
+    /// `structured-error-crate-globs`
+    pub crate_globs: Vec<String>,
+    /// `flag-inconsistent-error-types`
+    pub flag_inconsistent: bool,
 }
 
-impl_lint_pass!(LibraryCratesStructuredErrors => [LIBRARY_CRATES_STRUCTURED_ERRORS]);
+/// Decides which crates and items the lints for the exported API of library crates check, see
+/// `library-error-check-crate-types` and the related configuration.
+pub(crate) struct LibraryScope {
+    is_library_crate: OnceCell<bool>,
+    check_crate_types: Vec<LibraryCrateType>,
+    check_binaries: bool,
+    crate_globs: Vec<String>,
+    lint_doc_hidden: bool,
+    /// The output directory of the build script, which generated code is written to.
+    out_dir: Option<PathBuf>,
+    /// The directory of the package's `Cargo.toml`, which Cargo looks for examples, benchmarks and
+    /// integration tests in.
+    package_root: Option<PathBuf>,
+}
 
-fn is_overly_generic_error_type(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
-    if is_type_lang_item(cx, ty, LangItem::String) {
-        return true;
+impl LibraryScope {
+    pub(crate) fn new(conf: &StructuredErrorsConf) -> Self {
+        Self {
+            is_library_crate: OnceCell::new(),
+            check_crate_types: conf.check_crate_types.clone(),
+            check_binaries: conf.check_binaries,
+            crate_globs: conf.crate_globs.clone(),
+            lint_doc_hidden: conf.lint_doc_hidden,
+            out_dir: env::var_os("OUT_DIR").map(PathBuf::from),
+            package_root: env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from),
+        }
     }
-    if is_type_lang_item(cx, ty, LangItem::OwnedBox) {
-        let inner = ty.boxed_ty();
-        if let ty::Dynamic(predicates, _, _) = inner.kind() {
-            if predicates.iter().any(|predicate| {
-                if let ExistentialPredicate::Trait(trait_) = predicate.skip_binder() {
-                    cx.tcx.is_diagnostic_item(sym::Error, trait_.def_id)
-                } else {
-                    false
-                }
-            }) {
-                return true;
-            }
+
+    /// Checks if the crate is one of the checked crate types. This is computed on first use rather
+    /// than in `check_crate`, so it doesn't depend on the order the pass's methods are called in.
+    pub(crate) fn is_library_crate(&self, cx: &LateContext<'_>) -> bool {
+        *self.is_library_crate.get_or_init(|| {
+            !self.is_test_or_example(cx)
+                && self.matches_crate_globs(cx)
+                && cx.tcx.crate_types().iter().any(|crate_type| {
+                    (self.check_binaries && *crate_type == CrateType::Executable)
+                        || self
+                            .check_crate_types
+                            .iter()
+                            .any(|checked| to_crate_type(*checked) == *crate_type)
+                })
+        })
+    }
+
+    /// Checks if the name of the crate matches one of the `structured-error-crate-globs`, if any
+    /// are configured.
+    fn matches_crate_globs(&self, cx: &LateContext<'_>) -> bool {
+        let crate_name = cx.tcx.crate_name(LOCAL_CRATE);
+        self.crate_globs.is_empty()
+            || self
+                .crate_globs
+                .iter()
+                .any(|glob| glob_matches(&glob.replace('-', "_"), crate_name.as_str()))
+    }
+
+    /// Checks if the crate is a test, benchmark or example target, which `--all-targets` compiles
+    /// as executables of their own. Nothing else can call into them, so even with
+    /// `enforce-structured-errors-in-binaries` they aren't checked.
+    fn is_test_or_example(&self, cx: &LateContext<'_>) -> bool {
+        // Test harnesses, including the unit tests of the library itself
+        if cx.sess().opts.test {
+            return true;
+        }
+        // Examples, benchmarks and integration tests without a harness are built like binaries, so
+        // only the directory Cargo finds them in tells them apart
+        if cx.tcx.crate_types().contains(&CrateType::Executable)
+            && let Some(package_root) = &self.package_root
+            && let Some(source_file) = cx.sess().local_crate_source_file()
+            && let Ok(current_dir) = env::current_dir()
+        {
+            is_in_target_dir(&current_dir.join(source_file), &current_dir.join(package_root))
+        } else {
+            false
         }
     }
-    if match ty.kind() {
-        ty::Adt(adt, _) => {
-            match_def_path(cx, adt.did(), &["anyhow", "Error"]) || match_def_path(cx, adt.did(), &["eyre", "Report"])
-        },
-        _ => false,
-    } {
-        return true;
+
+    /// Checks if the item was generated, by a macro or by the build script, or if it's hidden from
+    /// the documentation.
+    pub(crate) fn is_generated_or_hidden(&self, cx: &LateContext<'_>, def_id: LocalDefId, span: Span) -> bool {
+        span.from_expansion()
+            || self.is_in_out_dir(cx, span)
+            || (!self.lint_doc_hidden && is_doc_hidden_item(cx, def_id))
+    }
+
+    /// Checks if the span is in a file written by the build script, e.g. bindings that were
+    /// `include!`d from `OUT_DIR`.
+    fn is_in_out_dir(&self, cx: &LateContext<'_>, span: Span) -> bool {
+        if let Some(out_dir) = &self.out_dir
+            && let FileName::Real(file_name) = cx.sess().source_map().span_to_filename(span)
+            && let Some(path) = file_name.local_path()
+        {
+            path.starts_with(out_dir)
+        } else {
+            false
+        }
     }
-    false
 }
 
-fn is_library_crate(cx: &LateContext<'_>) -> bool {
-    cx.tcx.crate_types().iter().any(|t: &CrateType| {
-        matches!(
-            t,
-            CrateType::Cdylib | CrateType::Dylib | CrateType::Rlib | CrateType::ProcMacro | CrateType::Staticlib
-        )
-    })
+#[expect(clippy::struct_excessive_bools)]
+pub struct LibraryCratesStructuredErrors<'tcx> {
+    scope: LibraryScope,
+    allowed_error_types: Vec<String>,
+    allowed_def_ids: FxHashSet<DefId>,
+    framework_result_types: Vec<String>,
+    framework_result_def_ids: FxHashSet<DefId>,
+    summarize: bool,
+    collapse_per_impl: bool,
+    flag_integer_error_types: bool,
+    flag_foreign_error_types: bool,
+    flag_unbounded_generic_errors: bool,
+    flag_opaque_wrapper_errors: bool,
+    emit_count: bool,
+    flag_inconsistent: bool,
+    exempt_categories: Vec<UnstructuredErrorCategory>,
+    message_style: StructuredErrorMessageStyle,
+    visibility: StructuredErrorVisibility,
+    /// The error types found in the methods of `impl` blocks, which are reported together once the
+    /// `impl` block has been checked if `collapse_per_impl` is enabled.
+    pending_impl_lints: FxHashMap<LocalDefId, Vec<PendingMethodLint<'tcx>>>,
+    /// The reported error types and the functions they were found in, for the summary and the
+    /// violation count.
+    findings: Vec<(ErrorCategory, LocalDefId)>,
+    /// The names of the reported opaque error types and the first function each was found in, for
+    /// the check for inconsistent error types.
+    opaque_error_types: FxIndexMap<String, LocalDefId>,
+    /// The exported types that are named like an error type or used as the error type of an
+    /// exported function, along with the return type of one such function. They're checked for an
+    /// `Error` impl once the whole crate has been visited.
+    error_types: FxIndexMap<LocalDefId, Option<Span>>,
 }
 
-impl<'tcx> LateLintPass<'tcx> for LibraryCratesStructuredErrors {
-    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
-        if is_library_crate(cx) {
-            self.is_library_crate = Some(true);
+impl LibraryCratesStructuredErrors<'_> {
+    pub fn new(conf: StructuredErrorsConf) -> Self {
+        let scope = LibraryScope::new(&conf);
+        let StructuredErrorsConf {
+            allowed_error_types,
+            framework_result_types,
+            summarize,
+            collapse_per_impl,
+            flag_integer_error_types,
+            flag_foreign_error_types,
+            exempt_categories,
+            message_style,
+            visibility,
+            flag_unbounded_generic_errors,
+            flag_opaque_wrapper_errors,
+            emit_count,
+            flag_inconsistent,
+            ..
+        } = conf;
+        Self {
+            scope,
+            allowed_error_types,
+            allowed_def_ids: FxHashSet::default(),
+            framework_result_types,
+            framework_result_def_ids: FxHashSet::default(),
+            summarize,
+            collapse_per_impl,
+            flag_integer_error_types,
+            flag_foreign_error_types,
+            flag_unbounded_generic_errors,
+            flag_opaque_wrapper_errors,
+            emit_count,
+            flag_inconsistent,
+            exempt_categories,
+            message_style,
+            visibility,
+            pending_impl_lints: FxHashMap::default(),
+            findings: Vec::new(),
+            opaque_error_types: FxIndexMap::default(),
+            error_types: FxIndexMap::default(),
+        }
+    }
+
+    /// Returns the category of the error type if it's unstructured and wasn't allowed in the
+    /// configuration.
+    fn is_overly_generic_error_type<'tcx>(&self, cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<ErrorCategory> {
+        // `!` means the function can't fail, and `()` that there is only one way it can fail, so neither
+        // has any structure to add. `core::convert::Infallible` is matched by its path below.
+        if ty.is_unit() || ty.is_never() {
+            return None;
+        }
+        if let ty::Adt(adt, _) = ty.kind()
+            && is_structured_error_ok(cx, adt.did())
+        {
+            return None;
+        }
+        // Local types implementing `Error` are the structured error types the lint asks for, whatever
+        // the other heuristics would say about them
+        if let ty::Adt(adt, _) = ty.kind()
+            && adt.did().is_local()
+            && implements_error_trait(cx, ty)
+        {
+            return None;
+        }
+        if is_integer_code(ty) {
+            return self.flag_integer_error_types.then_some(ErrorCategory::Integer);
+        }
+        if let ty::Param(_) = ty.kind() {
+            return (self.flag_unbounded_generic_errors && is_unbounded_param(cx, ty))
+                .then_some(ErrorCategory::UnboundedGeneric);
+        }
+        if let ty::Adt(adt, _) = ty.kind()
+            && (self.allowed_def_ids.contains(&adt.did())
+                || match_def_path(cx, adt.did(), &paths::CORE_CONVERT_INFALLIBLE))
+        {
+            return None;
+        }
+        if is_unstructured_error_ty(cx, ty) {
+            Some(ErrorCategory::of(cx, ty)).filter(|&category| !self.is_exempt(category))
+        } else if self.flag_foreign_error_types && is_foreign_error_ty(cx, ty) {
+            Some(ErrorCategory::Foreign)
         } else {
-            self.is_library_crate = Some(false);
+            None
         }
     }
 
-    fn check_fn(
+    /// Checks if the item is an error type of the crate whose fields are all opaque error types,
+    /// e.g. `pub struct Error(#[from] anyhow::Error)`, see `flag_opaque_wrapper_errors`. Enums are
+    /// only checked if they have a single variant.
+    fn check_opaque_wrapper<'tcx>(&self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        let fields = match item.kind {
+            hir::ItemKind::Struct(ref data, _) => data.fields(),
+            hir::ItemKind::Enum(ref def, _) if let [variant] = def.variants => variant.data.fields(),
+            _ => return,
+        };
+        if fields.is_empty()
+            || self.skip_item(cx, item.owner_id.def_id, item.span)
+            || is_structured_error_ok(cx, item.owner_id.to_def_id())
+        {
+            return;
+        }
+        let ty = cx.tcx.type_of(item.owner_id).instantiate_identity();
+        if !item.ident.as_str().ends_with("Error") && !implements_error_trait(cx, ty) {
+            return;
+        }
+        let mut wrapped = Vec::with_capacity(fields.len());
+        for field in fields {
+            let field_ty = cx.tcx.type_of(field.def_id).instantiate_identity();
+            match self.is_overly_generic_error_type(cx, field_ty) {
+                Some(category) if is_opaque_error_ty(cx, category, field_ty) => {
+                    wrapped.push((field.ty.span, field_ty, category));
+                },
+                _ => return,
+            }
+        }
+        let (first_span, _, category) = wrapped[0];
+        let Some(lint) = enabled_lint(cx, category, item.hir_id()) else {
+            return;
+        };
+        span_lint_and_then(
+            cx,
+            lint,
+            cx.tcx.def_span(item.owner_id),
+            &format!(
+                "`{}` only wraps the opaque error type `{}`",
+                item.ident,
+                snippet(cx, first_span, "..")
+            ),
+            |diag| {
+                note_category(diag, category);
+                for (span, ..) in &wrapped {
+                    diag.span_label(*span, "");
+                }
+                diag.note("callers still can't match on the errors, the type merely forwards them");
+                add_advice(
+                    diag,
+                    self.message_style,
+                    "consider giving it variants for the errors of the crate instead",
+                );
+            },
+        );
+    }
+
+    /// Checks the public fields of structs and enum variants that hold an opaque error type, e.g.
+    /// `pub last_error: Option<anyhow::Error>`, which exposes the error as part of the state of the
+    /// type. The error type may be wrapped in `Option`s or be the error type of a `Result`. Error
+    /// types of the crate are checked by `check_opaque_wrapper` instead, and the configured
+    /// `framework-result-types` aren't checked.
+    fn check_error_fields<'tcx>(&self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        let variants = match item.kind {
+            hir::ItemKind::Struct(ref data, _) => vec![(None, data)],
+            hir::ItemKind::Enum(ref def, _) => def
+                .variants
+                .iter()
+                .map(|variant| (Some(variant.ident), &variant.data))
+                .collect(),
+            _ => return,
+        };
+        if self.skip_item(cx, item.owner_id.def_id, item.span)
+            || is_structured_error_ok(cx, item.owner_id.to_def_id())
+            || self.framework_result_def_ids.contains(&item.owner_id.to_def_id())
+            || implements_error_trait(cx, cx.tcx.type_of(item.owner_id).instantiate_identity())
+        {
+            return;
+        }
+        for (variant, data) in variants {
+            for field in data.fields() {
+                if self.skip_item(cx, field.def_id, field.span) {
+                    continue;
+                }
+                let field_ty = cx.tcx.type_of(field.def_id).instantiate_identity();
+                let mut err_ty = peel_to_result_err(cx, field_ty).unwrap_or(field_ty);
+                while let ty::Adt(adt, args) = err_ty.kind()
+                    && cx.tcx.is_diagnostic_item(sym::Option, adt.did())
+                {
+                    err_ty = args.type_at(0);
+                }
+                if let Some(category) = self.is_overly_generic_error_type(cx, err_ty)
+                    && is_opaque_error_ty(cx, category, err_ty)
+                    && let Some(lint) = enabled_lint(cx, category, field.hir_id)
+                {
+                    let owner = match variant {
+                        Some(variant) => format!("{}::{variant}", item.ident),
+                        None => item.ident.to_string(),
+                    };
+                    span_lint_hir_and_then(
+                        cx,
+                        lint,
+                        field.hir_id,
+                        field.span,
+                        &format!(
+                            "the public field `{}` of `{owner}` exposes an opaque error type",
+                            field.ident
+                        ),
+                        |diag| {
+                            note_category(diag, category);
+                            diag.span_label(field.ty.span, category.message(cx, err_ty));
+                            diag.note("callers can read the error, but can't match on it without downcasting");
+                            add_advice(
+                                diag,
+                                self.message_style,
+                                "consider storing a structured error type of the crate instead",
+                            );
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    /// Checks if the category was exempted in the configuration.
+    fn is_exempt(&self, category: ErrorCategory) -> bool {
+        self.exempt_categories
+            .iter()
+            .any(|&exempt| to_error_category(exempt) == category)
+    }
+
+    /// Finds an unstructured error type in a return type of `Result<_, E>`, or below it in
+    /// `Result<Result<_, E>, _>`, `Option<Result<_, E>>`, `Poll<Option<Result<_, E>>>`,
+    /// `Pin<Box<dyn Future<Output = Result<_, E>>>>` and the like, see `peel_to_result_err`. The
+    /// outer error type is preferred, so doubly unstructured results are reported once. Returns
+    /// the error type, its category and whether it was found in a nested `Result`.
+    fn find_unstructured_err_ty<'tcx>(
+        &self,
+        cx: &LateContext<'tcx>,
+        ty: Ty<'tcx>,
+    ) -> Option<(Ty<'tcx>, ErrorCategory, bool)> {
+        let ty::Adt(adt, args) = ty.kind() else {
+            return None;
+        };
+        let inner_ty = match cx.tcx.get_diagnostic_name(adt.did()) {
+            Some(sym::Result) => {
+                let err_ty = reveal_opaque_ty(cx, args.type_at(1));
+                if let Some(category) = self.is_overly_generic_error_type(cx, err_ty) {
+                    return Some((err_ty, category, false));
+                }
+                args.type_at(0)
+            },
+            Some(sym::Option) => args.type_at(0),
+            // Manual `poll` methods, like those of `Future` and `AsyncRead` impls
+            _ if cx.tcx.lang_items().get(LangItem::Poll) == Some(adt.did()) => args.type_at(0),
+            // The `Pin<Box<dyn Future<Output = Result<_, E>>>>` of `#[async_trait]` methods
+            _ if cx.tcx.lang_items().get(LangItem::Pin) == Some(adt.did()) => args.type_at(0),
+            _ => return None,
+        };
+        if let Some(err_ty) = peel_to_result_err(cx, inner_ty)
+            && let err_ty = reveal_opaque_ty(cx, err_ty)
+            && let Some(category) = self.is_overly_generic_error_type(cx, err_ty)
+        {
+            Some((err_ty, category, true))
+        } else {
+            None
+        }
+    }
+
+    /// Finds an unstructured error type in the return type of a function, see
+    /// `find_unstructured_err_ty`. Returns the error type, its category and the error type as
+    /// written, if it's visible in `hir_ty`. Nested error types of functions returning futures or
+    /// streams are left to the check of their outputs, which reports them where they're written.
+    fn find_return_err_ty<'tcx>(
+        &self,
+        cx: &LateContext<'tcx>,
+        hir_ty: &'tcx hir::Ty<'tcx>,
+        ret_ty: Ty<'tcx>,
+        body: &'tcx Body<'tcx>,
+        returns_future_or_stream: bool,
+    ) -> Option<(Ty<'tcx>, ErrorCategory, Option<&'tcx hir::Ty<'tcx>>)> {
+        if let Some((err_ty, category, is_nested)) = self.find_unstructured_err_ty(cx, ret_ty) {
+            (!is_nested || !returns_future_or_stream)
+                .then(|| (err_ty, category, written_err_hir_ty(cx, hir_ty, is_nested)))
+        } else if let hir::TyKind::OpaqueDef(..) = hir_ty.kind
+            && !returns_future_or_stream
+            // `impl Trait` return types may hide custom result-like wrappers, so fall back to
+            // the errors constructed in the body
+            && let Some(err_ty) = return_error_ty_through_body(cx, body)
+            && let Some(category) = self.is_overly_generic_error_type(cx, err_ty)
+        {
+            Some((err_ty, category, None))
+        } else {
+            None
+        }
+    }
+
+    /// Collects the unstructured components of a compound error type, e.g. both parts of
+    /// `(anyhow::Error, String)`, recursing into nested tuples. Each component comes with its type
+    /// as written, if the tuple is written out in `hir_ty`.
+    fn collect_err_components<'tcx>(
+        &self,
+        cx: &LateContext<'tcx>,
+        ty: Ty<'tcx>,
+        hir_ty: Option<&'tcx hir::Ty<'tcx>>,
+        components: &mut Vec<(Ty<'tcx>, ErrorCategory, Option<&'tcx hir::Ty<'tcx>>)>,
+    ) {
+        let ty::Tuple(tys) = ty.kind() else {
+            return;
+        };
+        let hir_tys = match hir_ty.map(|hir_ty| hir_ty.kind) {
+            Some(hir::TyKind::Tup(hir_tys)) if hir_tys.len() == tys.len() => Some(hir_tys),
+            _ => None,
+        };
+        for (i, component_ty) in tys.iter().enumerate() {
+            let component_hir_ty = hir_tys.map(|hir_tys| &hir_tys[i]);
+            if let Some(category) = self.is_overly_generic_error_type(cx, component_ty) {
+                components.push((component_ty, category, component_hir_ty));
+            } else {
+                self.collect_err_components(cx, component_ty, component_hir_ty, components);
+            }
+        }
+    }
+
+    /// Reports each unstructured component of a compound error type returned in `Result<_, E>`
+    /// separately, at the component if it's written out and at the return type otherwise. Tuples
+    /// that are unstructured as a whole, like `(u32, String)`, are reported by
+    /// `find_return_err_ty`.
+    fn check_compound_err_ty<'tcx>(
         &mut self,
         cx: &LateContext<'tcx>,
-        fn_kind: FnKind<'tcx>,
-        fn_: &'tcx FnDecl<'tcx>,
-        _: &'tcx Body<'tcx>,
-        span: Span,
-        local_def_id: LocalDefId,
+        def_id: LocalDefId,
+        suggestion_item: LocalDefId,
+        hir_ty: &'tcx hir::Ty<'tcx>,
+        ret_ty: Ty<'tcx>,
     ) {
-        if !self
-            .is_library_crate
-            .expect("Should have been initialized in check_crate")
-        {
+        let ty::Adt(adt, args) = ret_ty.kind() else {
+            return;
+        };
+        if !cx.tcx.is_diagnostic_item(sym::Result, adt.did()) {
             return;
         }
-        //We are looking for functions that return anyhow::Result or
-        // Result<_, Box<dyn Error>> or Result<_, String>
-        if let FnKind::Method(_, _) | FnKind::ItemFn(_, _, _) = fn_kind {
-            if let Some((hir_ty, err_ty)) = result_err_ty(cx, fn_, local_def_id, span)
-                && is_overly_generic_error_type(cx, err_ty)
-            {
-                span_lint_and_note(
+        let mut components = Vec::new();
+        let err_ty = reveal_opaque_ty(cx, args.type_at(1));
+        self.collect_err_components(cx, err_ty, result_err_hir_ty(cx, hir_ty), &mut components);
+        for (component_ty, category, component_hir_ty) in components {
+            if let Some(category) = emit_lint(
+                cx,
+                self.message_style,
+                def_id,
+                suggestion_item,
+                component_hir_ty.unwrap_or(hir_ty),
+                component_hir_ty,
+                component_ty,
+                category,
+            ) {
+                self.record_finding(cx, category, component_ty, def_id);
+            }
+        }
+    }
+
+    /// Checks the bounds of a type parameter that is returned as the error type, e.g.
+    /// `E: Into<anyhow::Error>` or `anyhow::Error: From<E>` in `fn f<E>() -> Result<(), E>`. The
+    /// error is only ever used by converting it into the opaque type, so that's the type the
+    /// function's errors effectively are.
+    fn check_error_param_bounds<'tcx>(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        def_id: LocalDefId,
+        suggestion_item: LocalDefId,
+        ret_ty: Ty<'tcx>,
+    ) {
+        let ty::Adt(adt, args) = ret_ty.kind() else {
+            return;
+        };
+        if !cx.tcx.is_diagnostic_item(sym::Result, adt.did()) {
+            return;
+        }
+        let err_ty = args.type_at(1);
+        let (ty::Param(_), Some(generics)) = (err_ty.kind(), cx.tcx.hir().get_generics(def_id)) else {
+            return;
+        };
+        for predicate in generics.predicates {
+            let hir::WherePredicate::BoundPredicate(predicate) = predicate else {
+                continue;
+            };
+            let bounded_ty = hir_ty_to_ty(cx.tcx, predicate.bounded_ty);
+            for bound in predicate.bounds {
+                let hir::GenericBound::Trait(poly_trait_ref, _) = bound else {
+                    continue;
+                };
+                let Some(trait_def_id) = poly_trait_ref.trait_ref.trait_def_id() else {
+                    continue;
+                };
+                let Some(&hir::GenericArg::Type(arg_hir_ty)) = poly_trait_ref
+                    .trait_ref
+                    .path
+                    .segments
+                    .last()
+                    .and_then(|segment| segment.args)
+                    .and_then(|args| args.args.first())
+                else {
+                    continue;
+                };
+                // `E: Into<Opaque>` or `Opaque: From<E>`
+                let (opaque_hir_ty, opaque_ty) =
+                    if bounded_ty == err_ty && cx.tcx.is_diagnostic_item(sym::Into, trait_def_id) {
+                        (arg_hir_ty, hir_ty_to_ty(cx.tcx, arg_hir_ty))
+                    } else if cx.tcx.is_diagnostic_item(sym::From, trait_def_id)
+                        && hir_ty_to_ty(cx.tcx, arg_hir_ty) == err_ty
+                    {
+                        (predicate.bounded_ty, bounded_ty)
+                    } else {
+                        continue;
+                    };
+                if let Some(opaque_ty) = normalize_ty(cx, def_id, opaque_ty)
+                    && let Some(category) = self.is_overly_generic_error_type(cx, opaque_ty)
+                    && let Some(category) = emit_lint(
+                        cx,
+                        self.message_style,
+                        def_id,
+                        suggestion_item,
+                        opaque_hir_ty,
+                        Some(opaque_hir_ty),
+                        opaque_ty,
+                        category,
+                    )
+                {
+                    self.record_finding(cx, category, opaque_ty, def_id);
+                }
+            }
+        }
+    }
+
+    /// Checks the defaults of the function's type parameters, e.g. `anyhow::Error` in
+    /// `fn f<E = anyhow::Error>() -> Result<(), E>`. Callers that don't specify the parameter get
+    /// the opaque error type. Only opaque error types are reported, as other defaults, like
+    /// `String`, are just as likely to be used for something other than errors.
+    fn check_param_defaults(&mut self, cx: &LateContext<'_>, def_id: LocalDefId, suggestion_item: LocalDefId) {
+        let Some(generics) = cx.tcx.hir().get_generics(def_id) else {
+            return;
+        };
+        for param in generics.params {
+            if let hir::GenericParamKind::Type {
+                default: Some(default), ..
+            } = param.kind
+                && let Some(ty) = normalize_ty(cx, def_id, hir_ty_to_ty(cx.tcx, default))
+                && let Some(category) = self.is_overly_generic_error_type(cx, ty)
+                && is_opaque_error_ty(cx, category, ty)
+                && let Some(category) = emit_lint(
                     cx,
-                    LIBRARY_CRATES_STRUCTURED_ERRORS,
-                    hir_ty.span,
-                    "this is an unstructured error type",
-                    None,
-                    "try using an error enum",
+                    self.message_style,
+                    def_id,
+                    suggestion_item,
+                    default,
+                    Some(default),
+                    ty,
+                    category,
+                )
+            {
+                self.record_finding(cx, category, ty, def_id);
+            }
+        }
+    }
+
+    /// Records the error type of a `Result` returned by a checked function if it's a type of the
+    /// crate, see `ERROR_TYPE_MISSING_ERROR_IMPL`.
+    fn record_error_type(&mut self, cx: &LateContext<'_>, hir_ty: &hir::Ty<'_>, ret_ty: Ty<'_>) {
+        if let ty::Adt(adt, args) = ret_ty.kind()
+            && cx.tcx.is_diagnostic_item(sym::Result, adt.did())
+            && let ty::Adt(err_adt, _) = args.type_at(1).kind()
+            && let Some(err_def_id) = err_adt.did().as_local()
+        {
+            let usage = self.error_types.entry(err_def_id).or_insert(None);
+            usage.get_or_insert(hir_ty.span);
+        }
+    }
+
+    /// Records a reported error type, along with the function it was found in. Opaque error types
+    /// are recorded by name as well, see `is_opaque_error_ty`.
+    fn record_finding(&mut self, cx: &LateContext<'_>, category: ErrorCategory, err_ty: Ty<'_>, def_id: LocalDefId) {
+        self.findings.push((category, def_id));
+        if is_opaque_error_ty(cx, category, err_ty) {
+            let name = if category.is_opaque() {
+                category.description().to_string()
+            } else {
+                format!("`{err_ty}`")
+            };
+            self.opaque_error_types.entry(name).or_insert(def_id);
+        }
+    }
+
+    /// Reports the crate if its exported functions use more than one opaque error type, see
+    /// `flag_inconsistent`. Each error type is pointed out in the first function it's used in.
+    fn check_inconsistent_error_types(&self, cx: &LateContext<'_>) {
+        let first_uses = &self.opaque_error_types;
+        if first_uses.len() < 2 {
+            return;
+        }
+        span_lint_and_then(
+            cx,
+            LIBRARY_CRATES_STRUCTURED_ERRORS,
+            DUMMY_SP,
+            &format!(
+                "the exported API of this crate uses {} different opaque error types",
+                first_uses.len()
+            ),
+            |diag| {
+                for (name, &def_id) in first_uses {
+                    diag.span_note(cx.tcx.def_span(def_id), format!("{name} is used here"));
+                }
+                add_advice(
+                    diag,
+                    self.message_style,
+                    "consider replacing all of them with a single structured error type",
                 );
+            },
+        );
+    }
+
+    /// Reports the recorded error types that don't implement `Error`.
+    fn check_missing_error_impls(&self, cx: &LateContext<'_>) {
+        let krate = if is_no_std_crate(cx) { "core" } else { "std" };
+        for (&def_id, &usage) in &self.error_types {
+            let ty = cx.tcx.type_of(def_id).instantiate_identity();
+            // The `Error` impl of a generic type may depend on its parameters
+            if !cx.tcx.generics_of(def_id).params.is_empty()
+                || implements_error_trait(cx, ty)
+                || is_structured_error_ok(cx, def_id.to_def_id())
+            {
+                continue;
+            }
+            let hir_id = cx.tcx.local_def_id_to_hir_id(def_id);
+            let name = cx.tcx.item_name(def_id.to_def_id());
+            let item_span = cx.tcx.hir().expect_item(def_id).span;
+            span_lint_hir_and_then(
+                cx,
+                ERROR_TYPE_MISSING_ERROR_IMPL,
+                hir_id,
+                cx.tcx.def_span(def_id),
+                &format!("`{name}` doesn't implement `{krate}::error::Error`"),
+                |diag| {
+                    if let Some(usage) = usage {
+                        diag.span_note(usage, format!("`{name}` is used as an error type here"));
+                    }
+                    let missing = [(sym::Debug, "Debug"), (sym::Display, "Display")]
+                        .into_iter()
+                        .filter(|&(diag_item, _)| {
+                            cx.tcx
+                                .get_diagnostic_item(diag_item)
+                                .is_some_and(|trait_id| !implements_trait(cx, ty, trait_id, &[]))
+                        })
+                        .map(|(_, name)| name)
+                        .collect::<Vec<_>>();
+                    let applicability = match *missing {
+                        [] => Applicability::MachineApplicable,
+                        [missing] => {
+                            diag.note(format!("`{krate}::error::Error` requires a `{missing}` impl as well"));
+                            Applicability::MaybeIncorrect
+                        },
+                        _ => {
+                            diag.note(format!(
+                                "`{krate}::error::Error` requires `{}` impls as well",
+                                missing.join("` and `")
+                            ));
+                            Applicability::MaybeIncorrect
+                        },
+                    };
+                    let indent = snippet_indent(cx, item_span).unwrap_or_default();
+                    diag.span_suggestion_verbose(
+                        item_span.shrink_to_hi(),
+                        "implement the trait",
+                        format!("\n\n{indent}impl {krate}::error::Error for {name} {{}}"),
+                        applicability,
+                    );
+                },
+            );
+        }
+    }
+
+    /// Checks if the return type is one of the configured `framework-result-types`, either as
+    /// written, e.g. through an alias, or after resolving aliases.
+    fn is_framework_result(&self, hir_ty: &hir::Ty<'_>, ret_ty: Ty<'_>) -> bool {
+        if let hir::TyKind::Path(hir::QPath::Resolved(None, path)) = hir_ty.kind
+            && let Res::Def(_, def_id) = path.res
+            && self.framework_result_def_ids.contains(&def_id)
+        {
+            return true;
+        }
+        matches!(ret_ty.kind(), ty::Adt(adt, _) if self.framework_result_def_ids.contains(&adt.did()))
+    }
+
+    /// Checks the parameters of the function for opaque error types, which force callers to erase
+    /// the type of their errors. Strings and the like are just as likely to be regular parameters,
+    /// so they aren't reported.
+    fn check_params<'tcx>(&self, cx: &LateContext<'tcx>, def_id: LocalDefId, decl: &'tcx FnDecl<'tcx>) {
+        let hir_id = cx.tcx.local_def_id_to_hir_id(def_id);
+        let lint = match cx.tcx.lint_level_at_node(LIBRARY_ERROR_PARAMETERS, hir_id) {
+            (Level::Allow, LintLevelSource::Default) => LIBRARY_CRATES_STRUCTURED_ERRORS,
+            (Level::Allow, _) => return,
+            _ => LIBRARY_ERROR_PARAMETERS,
+        };
+        if is_lint_allowed(cx, lint, hir_id) {
+            return;
+        }
+        let fn_sig = cx
+            .tcx
+            .instantiate_bound_regions_with_erased(cx.tcx.fn_sig(def_id).instantiate_identity());
+        for (hir_ty, &ty) in decl.inputs.iter().zip(fn_sig.inputs()) {
+            // `&anyhow::Error` still requires callers to have one
+            if let Some(ty) = normalize_ty(cx, def_id, ty).map(Ty::peel_refs)
+                && let Some(category) = self.is_overly_generic_error_type(cx, ty)
+                && is_opaque_error_ty(cx, category, ty)
+            {
+                let krate = if is_no_std_crate(cx) { "core" } else { "std" };
+                span_lint_hir_and_then(cx, lint, hir_id, hir_ty.span, &category.message(cx, ty), |diag| {
+                    note_category(diag, category);
+                    if let Some(ident) = cx.tcx.opt_item_ident(def_id.to_def_id())
+                        && !ident.span.from_expansion()
+                    {
+                        diag.span_label(ident.span, function_label(cx, def_id));
+                    }
+                    add_advice(
+                        diag,
+                        self.message_style,
+                        format!(
+                            "consider taking a `&dyn {krate}::error::Error`, a generic error type or a structured error type instead"
+                        ),
+                    );
+                });
+            }
+        }
+    }
+
+    /// Reports `?` that converts an opaque error into the structured error type of the function
+    /// it's used in, see `OPAQUE_ERROR_PROPAGATION`.
+    fn check_opaque_propagation<'tcx>(&self, cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) {
+        // `expr?` is desugared to `match Try::branch(expr) { .. }`
+        let ExprKind::Match(scrutinee, _, MatchSource::TryDesugar(_)) = e.kind else {
+            return;
+        };
+        let ExprKind::Call(_, [operand]) = scrutinee.kind else {
+            return;
+        };
+        let owner = cx.tcx.hir().get_parent_item(e.hir_id).def_id;
+        if !matches!(cx.tcx.def_kind(owner), DefKind::Fn | DefKind::AssocFn)
+            // `?` in closures and `async` blocks returns from them rather than from the function
+            || cx.tcx.hir().enclosing_body_owner(e.hir_id) != owner
+            || self.skip_item(cx, owner, e.span)
+            || is_accepted_unstructured_error(cx, owner)
+            || is_in_test_function(cx.tcx, e.hir_id)
+            || is_in_cfg_test(cx.tcx, e.hir_id)
+        {
+            return;
+        }
+        let ret_ty = cx
+            .tcx
+            .instantiate_bound_regions_with_erased(cx.tcx.fn_sig(owner).instantiate_identity())
+            .output();
+        if let ty::Adt(adt, args) = cx.typeck_results().expr_ty(operand).kind()
+            && cx.tcx.is_diagnostic_item(sym::Result, adt.did())
+            && let err_ty = reveal_opaque_ty(cx, args.type_at(1))
+            && let Some(category) = self.is_overly_generic_error_type(cx, err_ty)
+            && is_opaque_error_ty(cx, category, err_ty)
+            && let Some(ret_ty) = normalize_ty(cx, owner, ret_ty)
+            && let ty::Adt(ret_adt, ret_args) = ret_ty.kind()
+            && cx.tcx.is_diagnostic_item(sym::Result, ret_adt.did())
+            && let ret_err_ty = ret_args.type_at(1)
+            && let ty::Adt(..) = ret_err_ty.kind()
+            // Unstructured return types are reported by `library_crates_structured_errors`
+            && self.is_overly_generic_error_type(cx, ret_err_ty).is_none()
+        {
+            span_lint_hir_and_then(
+                cx,
+                OPAQUE_ERROR_PROPAGATION,
+                e.hir_id,
+                e.span,
+                &format!("`?` converts the opaque error type `{err_ty}` into `{ret_err_ty}`"),
+                |diag| {
+                    diag.span_label(operand.span, format!("this has the error type `{err_ty}`"));
+                    diag.note(format!(
+                        "callers can't match on the errors that `{ret_err_ty}` wraps this way"
+                    ));
+                    add_advice(
+                        diag,
+                        self.message_style,
+                        "consider returning a structured error type from the operand as well",
+                    );
+                },
+            );
+        }
+    }
+
+    /// Checks if the function is a trait impl method whose error type is dictated by the trait. The
+    /// author can only change the signature if the trait is defined in the crate, and if the error
+    /// type is written in the trait's declaration of the method, it's reported there instead.
+    fn is_dictated_by_trait(&self, cx: &LateContext<'_>, def_id: LocalDefId) -> bool {
+        if !is_trait_impl_item(cx, cx.tcx.local_def_id_to_hir_id(def_id)) {
+            return false;
+        }
+        let Some(trait_item_def_id) = cx.tcx.associated_item(def_id).trait_item_def_id else {
+            return true;
+        };
+        if !trait_item_def_id.is_local() {
+            return true;
+        }
+        let output = cx
+            .tcx
+            .instantiate_bound_regions_with_erased(cx.tcx.fn_sig(trait_item_def_id).instantiate_identity().output());
+        self.find_unstructured_err_ty(cx, output).is_some()
+    }
+
+    /// Checks if the item is out of scope of the lint, i.e. if it isn't visible enough to be
+    /// checked, if it isn't part of a library crate, if it was generated, or if it's hidden from
+    /// the documentation.
+    fn skip_item(&self, cx: &LateContext<'_>, def_id: LocalDefId, span: Span) -> bool {
+        !self.scope.is_library_crate(cx)
+            || !self.is_visible_enough(cx, def_id)
+            || self.scope.is_generated_or_hidden(cx, def_id, span)
+    }
+
+    /// Checks if the item has at least the visibility configured with
+    /// `structured-error-visibility`.
+    fn is_visible_enough(&self, cx: &LateContext<'_>, def_id: LocalDefId) -> bool {
+        match self.visibility {
+            StructuredErrorVisibility::Public => cx.effective_visibilities.is_exported(def_id),
+            // This includes private items of the crate root, which are visible in all its modules
+            StructuredErrorVisibility::Crate => {
+                cx.effective_visibilities.is_exported(def_id)
+                    || cx.tcx.visibility(def_id).is_accessible_from(CRATE_DEF_ID, cx.tcx)
+            },
+            StructuredErrorVisibility::All => true,
+        }
+    }
+
+    /// Checks the generic arguments of bounds on associated types, e.g. `anyhow::Error` in
+    /// `type Error: Into<anyhow::Error>`.
+    fn check_bounds<'tcx>(
+        &self,
+        cx: &LateContext<'tcx>,
+        owner: LocalDefId,
+        trait_def_id: LocalDefId,
+        bounds: &'tcx [hir::GenericBound<'tcx>],
+    ) {
+        for bound in bounds {
+            if let hir::GenericBound::Trait(poly_trait_ref, _) = bound
+                && let Some(args) = poly_trait_ref
+                    .trait_ref
+                    .path
+                    .segments
+                    .last()
+                    .and_then(|segment| segment.args)
+            {
+                let arg_tys = args.args.iter().filter_map(|arg| match arg {
+                    hir::GenericArg::Type(ty) => Some(*ty),
+                    _ => None,
+                });
+                let binding_tys = args.bindings.iter().filter_map(|binding| match binding.kind {
+                    hir::TypeBindingKind::Equality {
+                        term: hir::Term::Ty(ty),
+                    } => Some(ty),
+                    _ => None,
+                });
+                for hir_ty in arg_tys.chain(binding_tys) {
+                    if let Some(ty) = normalize_ty(cx, owner, hir_ty_to_ty(cx.tcx, hir_ty))
+                        && let Some(category) = self.is_overly_generic_error_type(cx, ty)
+                    {
+                        emit_lint(
+                            cx,
+                            self.message_style,
+                            owner,
+                            trait_def_id,
+                            hir_ty,
+                            Some(hir_ty),
+                            ty,
+                            category,
+                        );
+                    }
+                }
             }
         }
     }
 }
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Finds exported functions of library crates that return `String`, or another string type
+    /// like `Box<str>` or `Cow<str>`, as their error type.
+    ///
+    /// String errors are also reported by `library_crates_structured_errors`, unless this lint
+    /// has been configured explicitly.
+    ///
+    /// ### Why is this bad?
+    /// Users of the library can only match on the error message, which is not part of the API
+    /// and may change at any time.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// pub fn parse(input: &str) -> Result<u32, String> {
+    ///     input.parse().map_err(|_| format!("invalid number: {input}"))
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// #[derive(Debug)]
+    /// pub enum ParseError {
+    ///     InvalidNumber,
+    /// }
+    ///
+    /// pub fn parse(input: &str) -> Result<u32, ParseError> {
+    ///     input.parse().map_err(|_| ParseError::InvalidNumber)
+    /// }
+    /// ```
+    #[clippy::version = "1.77.0"]
+    pub LIBRARY_STRING_ERRORS,
+    restriction,
+    "library crates that use `String` as an error type"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Finds exported functions of library crates that return `Box<dyn Error>` as their error type,
+    /// or `Box<dyn Any>`, e.g. the payload of a caught panic.
+    ///
+    /// Boxed errors are also reported by `library_crates_structured_errors`, unless this lint
+    /// has been configured explicitly.
+    ///
+    /// ### Why is this bad?
+    /// Users of the library have to downcast the error to find out what went wrong.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// pub fn read_config() -> Result<String, Box<dyn std::error::Error>> {
+    ///     Ok(std::fs::read_to_string("config.toml")?)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// #[derive(Debug)]
+    /// pub enum ConfigError {
+    ///     Io(std::io::Error),
+    /// }
+    ///
+    /// pub fn read_config() -> Result<String, ConfigError> {
+    ///     std::fs::read_to_string("config.toml").map_err(ConfigError::Io)
+    /// }
+    /// ```
+    #[clippy::version = "1.77.0"]
+    pub LIBRARY_BOXED_ERRORS,
+    restriction,
+    "library crates that use `Box<dyn Error>` as an error type"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Finds `From<String>` and `From<&str>` impls for the exported error types of library crates.
+    ///
+    /// ### Why is this bad?
+    /// Such impls let callers turn any message into the error with `?` or `.into()`, so the error
+    /// type turns into a `String` error in disguise over time. Users of the library can only match
+    /// on the message of these errors.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::fmt;
+    /// #[derive(Debug)]
+    /// pub enum ConfigError {
+    ///     Io(std::io::Error),
+    ///     Other(String),
+    /// }
+    ///
+    /// impl From<String> for ConfigError {
+    ///     fn from(message: String) -> Self {
+    ///         Self::Other(message)
+    ///     }
+    /// }
+    /// # impl fmt::Display for ConfigError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { todo!() }
+    /// # }
+    /// # impl std::error::Error for ConfigError {}
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::fmt;
+    /// #[derive(Debug)]
+    /// pub enum ConfigError {
+    ///     Io(std::io::Error),
+    ///     MissingKey { key: String },
+    /// }
+    /// # impl fmt::Display for ConfigError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { todo!() }
+    /// # }
+    /// # impl std::error::Error for ConfigError {}
+    /// ```
+    #[clippy::version = "1.77.0"]
+    pub ERROR_FROM_STRING,
+    restriction,
+    "`From<String>` impls for error types of library crates"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Finds exported functions of library crates that take an opaque error type as a parameter,
+    /// i.e. `Box<dyn Error>` or the catch-all error type of an error handling crate like
+    /// `anyhow::Error`.
+    ///
+    /// These parameters are also reported by `library_crates_structured_errors`, unless this lint
+    /// has been configured explicitly.
+    ///
+    /// ### Why is this bad?
+    /// Callers have to erase the type of their errors to call the function, and can only do so
+    /// with the error handling crate the library chose.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// pub fn report(error: anyhow::Error) {
+    ///     eprintln!("{error}");
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// pub fn report(error: &dyn std::error::Error) {
+    ///     eprintln!("{error}");
+    /// }
+    /// ```
+    #[clippy::version = "1.77.0"]
+    pub LIBRARY_ERROR_PARAMETERS,
+    restriction,
+    "library crates that take opaque error types as parameters"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Finds exported types of library crates that are used as the error type of an exported
+    /// function, or are named like an error type, but don't implement `std::error::Error`.
+    ///
+    /// ### Why is this bad?
+    /// The errors can't be converted to `Box<dyn Error>` or the error types of error handling
+    /// crates with `?`, or be the source of another error.
+    ///
+    /// ### Known problems
+    /// Generic types aren't checked, as their `Error` impl may depend on their parameters.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// #[derive(Debug)]
+    /// pub struct ParseError;
+    ///
+    /// impl std::fmt::Display for ParseError {
+    ///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    ///         f.write_str("invalid input")
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// #[derive(Debug)]
+    /// pub struct ParseError;
+    ///
+    /// impl std::fmt::Display for ParseError {
+    ///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    ///         f.write_str("invalid input")
+    ///     }
+    /// }
+    ///
+    /// impl std::error::Error for ParseError {}
+    /// ```
+    #[clippy::version = "1.77.0"]
+    pub ERROR_TYPE_MISSING_ERROR_IMPL,
+    restriction,
+    "error types of library crates that don't implement `std::error::Error`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Finds errors in exported functions of library crates that are turned into a `String` with
+    /// `map_err` and handled right away, e.g. with `unwrap_or_else`, even if the error type is
+    /// structured.
+    ///
+    /// Errors that are turned into a `String` and propagated are reported by
+    /// `library_string_errors` instead.
+    ///
+    /// ### Why is this bad?
+    /// Only the message of the error is left to handle it, so it can't be matched on and its
+    /// source chain is lost.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// pub fn port(input: &str) -> u16 {
+    ///     input
+    ///         .parse()
+    ///         .map_err(|e| format!("{e}"))
+    ///         .unwrap_or_else(|message| {
+    ///             eprintln!("{message}");
+    ///             8080
+    ///         })
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// pub fn port(input: &str) -> u16 {
+    ///     input.parse().unwrap_or_else(|e: std::num::ParseIntError| {
+    ///         eprintln!("{e}");
+    ///         8080
+    ///     })
+    /// }
+    /// ```
+    #[clippy::version = "1.77.0"]
+    pub STRINGIFIED_ERROR_HANDLING,
+    restriction,
+    "errors of library crates that are turned into strings before they're handled"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Finds `?` in exported functions of library crates that converts an opaque error, like
+    /// `anyhow::Error` or `Box<dyn Error>`, into the structured error type the function returns.
+    ///
+    /// ### Why is this bad?
+    /// The conversion needs a `From` impl for the opaque error, which can only wrap it as a
+    /// whole. The structured error type doesn't tell callers anything about these errors, and they
+    /// can't be matched on.
+    ///
+    /// ### Known problems
+    /// `?` in closures and `async` blocks, including the bodies of `async fn`s, isn't checked.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::error::Error;
+    /// #[derive(Debug)]
+    /// pub struct LoadError(Box<dyn Error>);
+    ///
+    /// impl From<Box<dyn Error>> for LoadError {
+    ///     fn from(error: Box<dyn Error>) -> Self {
+    ///         Self(error)
+    ///     }
+    /// }
+    ///
+    /// fn read_config() -> Result<String, Box<dyn Error>> {
+    ///     todo!()
+    /// }
+    ///
+    /// pub fn load() -> Result<String, LoadError> {
+    ///     Ok(read_config()?)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// #[derive(Debug)]
+    /// pub enum LoadError {
+    ///     Io(std::io::Error),
+    /// }
+    ///
+    /// impl From<std::io::Error> for LoadError {
+    ///     fn from(error: std::io::Error) -> Self {
+    ///         Self::Io(error)
+    ///     }
+    /// }
+    ///
+    /// fn read_config() -> Result<String, std::io::Error> {
+    ///     todo!()
+    /// }
+    ///
+    /// pub fn load() -> Result<String, LoadError> {
+    ///     Ok(read_config()?)
+    /// }
+    /// ```
+    #[clippy::version = "1.77.0"]
+    pub OPAQUE_ERROR_PROPAGATION,
+    restriction,
+    "opaque errors converted into structured error types of library crates with `?`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Finds exported error enums of library crates with a catch-all variant that only wraps a
+    /// `String`, like `Other(String)` or `Custom { msg: String }`.
+    ///
+    /// ### Why is this bad?
+    /// The errors that end up in the catch-all variant are as unstructured as a `String` error
+    /// type. Callers can't match on them, and new errors tend to be added to it rather than get a
+    /// variant of their own.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// #[derive(Debug)]
+    /// pub enum ConfigError {
+    ///     Io(std::io::Error),
+    ///     Other(String),
+    /// }
+    /// # impl std::fmt::Display for ConfigError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { todo!() }
+    /// # }
+    /// impl std::error::Error for ConfigError {}
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// #[derive(Debug)]
+    /// pub enum ConfigError {
+    ///     Io(std::io::Error),
+    ///     MissingKey { key: String },
+    /// }
+    /// # impl std::fmt::Display for ConfigError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { todo!() }
+    /// # }
+    /// impl std::error::Error for ConfigError {}
+    /// ```
+    #[clippy::version = "1.77.0"]
+    pub ERROR_ENUM_STRINGLY_CATCHALL,
+    restriction,
+    "error enums of library crates with a catch-all variant wrapping a `String`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Reports the unstructured error types of functions marked with
+    /// `#[clippy::structured_error_todo]`, which are not reported by
+    /// `library_crates_structured_errors` and its siblings.
+    ///
+    /// ### Why is this bad?
+    /// It isn't, the attribute marks functions whose error types are known to be unstructured
+    /// while a crate is migrated to structured error types. Enabling this lint lists the
+    /// remaining ones.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// #[clippy::structured_error_todo]
+    /// pub fn load() -> Result<String, String> {
+    ///     todo!()
+    /// }
+    /// ```
+    #[clippy::version = "1.77.0"]
+    pub KNOWN_UNSTRUCTURED_ERRORS,
+    restriction,
+    "unstructured error types of library crates that are marked to be migrated"
+}
+
+impl_lint_pass!(LibraryCratesStructuredErrors<'_> => [
+    LIBRARY_CRATES_STRUCTURED_ERRORS,
+    LIBRARY_STRING_ERRORS,
+    LIBRARY_BOXED_ERRORS,
+    ERROR_FROM_STRING,
+    LIBRARY_ERROR_PARAMETERS,
+    ERROR_TYPE_MISSING_ERROR_IMPL,
+    STRINGIFIED_ERROR_HANDLING,
+    OPAQUE_ERROR_PROPAGATION,
+    ERROR_ENUM_STRINGLY_CATCHALL,
+    KNOWN_UNSTRUCTURED_ERRORS,
+]);
+
+/// Suggested error enums should be `#[non_exhaustive]`, they can't be extended otherwise.
+const NON_EXHAUSTIVE_HELP: &str =
+    "mark the error enum `#[non_exhaustive]`, so variants can be added without a breaking change";
+
+/// The names of methods that tear down a resource explicitly, whose errors are the most important
+/// ones for callers to match on.
+const FINALIZER_METHODS: [&str; 4] = ["close", "flush", "commit", "shutdown"];
+
+/// The names of the methods that finish a builder, whose errors tell callers which part of the
+/// configuration is wrong.
+const BUILDER_METHODS: [&str; 3] = ["build", "try_build", "finish"];
+
+/// The names of error enum variants that collect the errors which don't have a variant of their
+/// own, see `ERROR_ENUM_STRINGLY_CATCHALL`.
+const CATCH_ALL_VARIANTS: [&str; 7] = ["Other", "Msg", "Message", "Custom", "Generic", "Unknown", "Misc"];
+
+/// Reports the unstructured error type `err_ty` of the given category, which is part of the type
+/// written as `hir_ty`. `written_err_hir_ty` is the error type as written, if it's visible in
+/// `hir_ty`. If `owner` is a function, its name is labeled as well, and methods named like
+/// finalizers or builder methods get a stronger message. Suggestions are inserted in front of
+/// `suggestion_item`. Returns the category if the lint is enabled for it. Error types of functions
+/// marked with `#[clippy::structured_error_todo]` are reported with `KNOWN_UNSTRUCTURED_ERRORS`
+/// instead, and aren't counted as findings.
+///
+/// The lint level is taken from `owner` rather than from the node the pass is visiting, so
+/// `allow` attributes on any of the modules or `impl` blocks `owner` is nested in apply.
+#[expect(clippy::too_many_arguments, clippy::too_many_lines)]
+fn emit_lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    message_style: StructuredErrorMessageStyle,
+    owner: LocalDefId,
+    suggestion_item: LocalDefId,
+    hir_ty: &'tcx hir::Ty<'tcx>,
+    written_err_hir_ty: Option<&'tcx hir::Ty<'tcx>>,
+    err_ty: Ty<'tcx>,
+    category: ErrorCategory,
+) -> Option<ErrorCategory> {
+    let hir_id = cx.tcx.local_def_id_to_hir_id(owner);
+    let span = err_ty_span(hir_ty, written_err_hir_ty);
+    if is_structured_error_todo(cx, owner) {
+        span_lint_hir_and_then(
+            cx,
+            KNOWN_UNSTRUCTURED_ERRORS,
+            hir_id,
+            span,
+            "known unstructured error, migration pending",
+            |diag| {
+                note_category(diag, category);
+                diag.note(category.message(cx, err_ty));
+            },
+        );
+        return None;
+    }
+    let lint = enabled_lint(cx, category, hir_id)?;
+    if is_allowed_on_alias(cx, lint, hir_ty, written_err_hir_ty) {
+        return None;
+    }
+    if let Some(abi) = foreign_abi(cx, owner) {
+        emit_ffi_lint(cx, message_style, lint, owner, span, err_ty, category, abi);
+        return Some(category);
+    }
+    let finalizer = finalizer_name(cx, owner);
+    let builder = builder_method_name(cx, owner);
+    let returned_by = finalizer
+        .map(|name| format!("the finalizer `{name}`"))
+        .or_else(|| builder.map(|name| format!("the builder method `{name}`")));
+    let method_of = trait_of_method(cx, owner);
+    let sealed_trait = method_of
+        .map(|(trait_def_id, _)| trait_def_id)
+        .filter(|&trait_def_id| is_sealed_trait(cx, trait_def_id))
+        .map(|trait_def_id| cx.tcx.item_name(trait_def_id.to_def_id()));
+    let msg = match (returned_by, sealed_trait) {
+        (Some(method), Some(trait_name)) => format!(
+            "{}, returned by {method} of the sealed trait `{trait_name}`",
+            category.message(cx, err_ty)
+        ),
+        (Some(method), None) => format!("{}, returned by {method}", category.message(cx, err_ty)),
+        (None, Some(trait_name)) => format!(
+            "{}, returned by a method of the sealed trait `{trait_name}`",
+            category.message(cx, err_ty)
+        ),
+        (None, None) => category.message(cx, err_ty),
+    };
+    span_lint_hir_and_then(cx, lint, hir_id, span, &msg, |diag| {
+        note_category(diag, category);
+        if let Some(name) = finalizer {
+            diag.note(format!(
+                "the errors of `{name}` are the last chance to react to a failure of the resource, \
+                e.g. to retry or to report lost data, so callers need to tell them apart"
+            ));
+        }
+        if let Some(name) = builder {
+            diag.note(format!(
+                "the errors of `{name}` benefit most from structure: they're how callers find out which part \
+                of the configuration is invalid, and which setting to fix"
+            ));
+        }
+        if matches!(cx.tcx.def_kind(owner), DefKind::Fn | DefKind::AssocFn)
+            && let Some(ident) = cx.tcx.opt_item_ident(owner.to_def_id())
+            && !ident.span.from_expansion()
+        {
+            let label = match method_of {
+                Some((_, true)) => "in this trait default method",
+                Some((_, false)) => "in this trait method",
+                None => function_label(cx, owner),
+            };
+            diag.span_label(ident.span, label);
+        }
+        if let Some(trait_name) = sealed_trait {
+            diag.note(format!(
+                "`{trait_name}` is sealed, so its error types are effectively frozen: other crates can only \
+                call its methods, and changing the errors later is a breaking change"
+            ));
+        } else if let Some((trait_def_id, _)) = method_of {
+            diag.note(format!(
+                "the signature is shared by all implementors of `{}`, which have to return this error type as well",
+                cx.tcx.item_name(trait_def_id.to_def_id())
+            ));
+        }
+        if is_err_ty_hidden(cx, written_err_hir_ty)
+            // The error type of `anyhow::Result<T>` is known to anyone using it, unlike that of an alias
+            // defined by the crate
+            && (written_err_hir_ty.is_some() || !is_error_crate_result_path(cx, hir_ty))
+        {
+            diag.note(format!("the error type resolves to `{err_ty}`"));
+        }
+        note_local_aliases(cx, diag, hir_ty, written_err_hir_ty);
+        if category == ErrorCategory::Anyhow {
+            note_anyhow_context_calls(cx, diag, owner);
+        }
+        let krate = if is_no_std_crate(cx) { "core" } else { "std" };
+        // `thiserror` 1.x only supports `std`
+        let derive_thiserror = krate == "std" && depends_on_crate(cx, "thiserror");
+        if category == ErrorCategory::Foreign
+            && let Some(krate) = defining_crate_name(cx, err_ty)
+        {
+            diag.note(format!(
+                "this makes `{krate}` part of the public API, and updating it a breaking change"
+            ));
+        }
+        if is_boxed_dyn_any(cx, err_ty) {
+            diag.note(
+                "`Any` erases all error information, callers can't even display the error without downcasting it",
+            );
+        }
+        if category == ErrorCategory::UnboundedGeneric {
+            add_advice(
+                diag,
+                message_style,
+                format!(
+                    "return a concrete error type, or bound `{err_ty}` by a trait that gives it a meaning, \
+                    e.g. `{err_ty}: From<std::io::Error>`"
+                ),
+            );
+        }
+        // `color_eyre::Report` is `eyre::Report`, its hooks only change how reports are displayed
+        if category == ErrorCategory::Eyre && depends_on_crate(cx, "color_eyre") {
+            diag.note("`color_eyre` reports are meant for binaries, the report is still an `eyre::Report`");
+        }
+        if hir_ty.span.from_expansion() {
+            add_advice(diag, message_style, "try using an error enum");
+            add_advice(diag, message_style, NON_EXHAUSTIVE_HELP);
+            return;
+        }
+        let name = error_type_name(cx);
+        let insert_span = item_insertion_span(cx, suggestion_item);
+        let indent = snippet_indent(cx, insert_span).unwrap_or_default();
+        // `String` errors can be wrapped mechanically, as long as the `String` is written out
+        if is_type_lang_item(cx, err_ty, LangItem::String)
+            && let Some(err_hir_ty) = written_err_hir_ty
+            && !is_err_ty_hidden(cx, Some(err_hir_ty))
+        {
+            // With `thiserror`, the newtype displays the message it wraps
+            let newtype = if derive_thiserror {
+                format!("#[derive(Debug, thiserror::Error)]\n#[error(\"{{0}}\")]\npub struct {name}(pub String);")
+            } else {
+                format!("#[derive(Debug)]\npub struct {name}(pub String);")
+            };
+            diag.multipart_suggestion_verbose(
+                "try wrapping the `String` in a newtype",
+                vec![
+                    (insert_span, indent_insertion(&newtype, &indent)),
+                    (err_hir_ty.span, name),
+                ],
+                Applicability::MaybeIncorrect,
+            );
+        } else if category == ErrorCategory::Integer
+            && let Some(err_hir_ty) = written_err_hir_ty
+            && !is_err_ty_hidden(cx, Some(err_hir_ty))
+        {
+            diag.multipart_suggestion_verbose(
+                "try using an error enum wrapping the code",
+                vec![
+                    (
+                        insert_span,
+                        indent_insertion(
+                            &format!("#[derive(Debug)]\npub enum {name} {{\n    Code({err_ty}),\n}}"),
+                            &indent,
+                        ),
+                    ),
+                    (err_hir_ty.span, name),
+                ],
+                Applicability::MaybeIncorrect,
+            );
+            add_advice(diag, message_style, NON_EXHAUSTIVE_HELP);
+        } else {
+            diag.span_suggestion_verbose(
+                insert_span,
+                "try using an error enum",
+                error_enum_skeleton(&name, krate, derive_thiserror, &indent),
+                Applicability::HasPlaceholders,
+            );
+            add_advice(diag, message_style, NON_EXHAUSTIVE_HELP);
+        }
+        if is_opaque_error_ty(cx, category, err_ty)
+            && !crate_root_defines_error(cx)
+            && let Some(aliased) = error_alias_target(cx, err_ty)
+        {
+            add_advice(
+                diag,
+                message_style,
+                format!(
+                    "to migrate gradually, start with a crate-level `pub type Error = {aliased};` that can be \
+                    replaced later"
+                ),
+            );
+        }
+        if let Some((insertion_span, insertion, applicability)) = error_crate_result_alias(cx, hir_ty) {
+            diag.span_suggestion_verbose(
+                insertion_span,
+                "or at least make the error type explicit",
+                insertion,
+                applicability,
+            );
+        }
+    });
+    Some(category)
+}
+
+/// Adds the machine-readable category of the reported error type, e.g. `category: anyhow`, so tools
+/// consuming the JSON output don't have to parse the message.
+fn note_category(diag: &mut Diagnostic, category: ErrorCategory) {
+    diag.note(format!("category: {}", category.name()));
+}
+
+/// Returns the label of the function the error type was found in.
+fn function_label(cx: &LateContext<'_>, def_id: LocalDefId) -> &'static str {
+    if cx.effective_visibilities.is_exported(def_id) {
+        "in this exported function"
+    } else {
+        "in this function"
+    }
+}
+
+/// Adds advice on how to structure the error type that isn't a code suggestion, as a note or as a
+/// help message depending on `structured-error-message-style`.
+fn add_advice(diag: &mut Diagnostic, style: StructuredErrorMessageStyle, advice: impl Into<SubdiagnosticMessage>) {
+    match style {
+        StructuredErrorMessageStyle::Note => diag.note(advice),
+        StructuredErrorMessageStyle::Help => diag.help(advice),
+    };
+}
+
+/// Checks if the item, or any item it's nested in, is marked `#[doc(hidden)]`.
+fn is_doc_hidden_item(cx: &LateContext<'_>, def_id: LocalDefId) -> bool {
+    let hir_id = cx.tcx.local_def_id_to_hir_id(def_id);
+    is_doc_hidden(cx.tcx.hir().attrs(hir_id))
+        || cx
+            .tcx
+            .hir()
+            .parent_iter(hir_id)
+            .any(|(id, _)| is_doc_hidden(cx.tcx.hir().attrs(id)))
+}
+
+/// Returns the trait the function is a method of, and whether the method has a default body.
+fn trait_of_method(cx: &LateContext<'_>, def_id: LocalDefId) -> Option<(LocalDefId, bool)> {
+    if let hir::Node::TraitItem(item) = cx.tcx.hir_node_by_def_id(def_id)
+        && let hir::TraitItemKind::Fn(_, trait_fn) = item.kind
+    {
+        Some((
+            cx.tcx.local_parent(def_id),
+            matches!(trait_fn, hir::TraitFn::Provided(_)),
+        ))
+    } else {
+        None
+    }
+}
+
+/// Checks if the trait is sealed, i.e. if it has a supertrait that isn't exported, so it can't be
+/// implemented outside of the crate.
+fn is_sealed_trait(cx: &LateContext<'_>, trait_def_id: LocalDefId) -> bool {
+    cx.effective_visibilities.is_exported(trait_def_id)
+        && cx
+            .tcx
+            .super_predicates_of(trait_def_id)
+            .predicates
+            .iter()
+            .filter_map(|(predicate, _)| predicate.as_trait_clause())
+            .filter_map(|clause| clause.def_id().as_local())
+            .any(|super_def_id| !cx.effective_visibilities.is_exported(super_def_id))
+}
+
+/// Returns the name of the method if it's one of the `FINALIZER_METHODS`.
+fn finalizer_name(cx: &LateContext<'_>, def_id: LocalDefId) -> Option<Symbol> {
+    if let DefKind::AssocFn = cx.tcx.def_kind(def_id)
+        && let name = cx.tcx.item_name(def_id.to_def_id())
+        && FINALIZER_METHODS.contains(&name.as_str())
+    {
+        Some(name)
+    } else {
+        None
+    }
+}
+
+/// Returns the name of the method if it's one of the `BUILDER_METHODS`.
+fn builder_method_name(cx: &LateContext<'_>, def_id: LocalDefId) -> Option<Symbol> {
+    if let DefKind::AssocFn = cx.tcx.def_kind(def_id)
+        && let name = cx.tcx.item_name(def_id.to_def_id())
+        && BUILDER_METHODS.contains(&name.as_str())
+    {
+        Some(name)
+    } else {
+        None
+    }
+}
+
+/// Checks if the type is written as `Self::Output`, the associated type of the trait impl the
+/// function is a method of.
+fn is_self_output(hir_ty: &hir::Ty<'_>) -> bool {
+    if let hir::TyKind::Path(hir::QPath::TypeRelative(self_ty, segment)) = hir_ty.kind
+        && let hir::TyKind::Path(hir::QPath::Resolved(None, path)) = self_ty.kind
+    {
+        matches!(path.res, Res::SelfTyAlias { .. }) && segment.ident.name == sym::Output
+    } else {
+        false
+    }
+}
+
+/// Returns the ABI of the function if it is meant to be called from foreign code, e.g.
+/// `extern "C"`.
+fn foreign_abi(cx: &LateContext<'_>, def_id: LocalDefId) -> Option<Abi> {
+    if matches!(cx.tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn) {
+        let abi = cx.tcx.fn_sig(def_id).skip_binder().abi();
+        (abi != Abi::Rust).then_some(abi)
+    } else {
+        None
+    }
+}
+
+/// Reports an unstructured error type in the signature of an `extern` function. Foreign callers
+/// can't use a Rust error type at all, so an error enum isn't suggested.
+#[expect(clippy::too_many_arguments)]
+fn emit_ffi_lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    message_style: StructuredErrorMessageStyle,
+    lint: &'static Lint,
+    owner: LocalDefId,
+    span: Span,
+    err_ty: Ty<'tcx>,
+    category: ErrorCategory,
+    abi: Abi,
+) {
+    span_lint_hir_and_then(
+        cx,
+        lint,
+        cx.tcx.local_def_id_to_hir_id(owner),
+        span,
+        &format!(
+            "this `extern \"{}\"` function uses the unstructured error type `{err_ty}`",
+            abi.name()
+        ),
+        |diag| {
+            note_category(diag, category);
+            if let Some(ident) = cx.tcx.opt_item_ident(owner.to_def_id())
+                && !ident.span.from_expansion()
+            {
+                diag.span_label(ident.span, function_label(cx, owner));
+            }
+            diag.note("Rust types like `Result` have no stable layout, so foreign code can't use the error");
+            add_advice(
+                diag,
+                message_style,
+                "consider returning an error code, or a `#[repr(C)]` error type",
+            );
+        },
+    );
+}
+
+/// Points at the type aliases of the local crate the error type is reached through, as those are
+/// the ones to fix.
+fn note_local_aliases<'tcx>(
+    cx: &LateContext<'tcx>,
+    diag: &mut Diagnostic,
+    hir_ty: &'tcx hir::Ty<'tcx>,
+    written_err_hir_ty: Option<&'tcx hir::Ty<'tcx>>,
+) {
+    let aliases = [Some(hir_ty), written_err_hir_ty]
+        .into_iter()
+        .flatten()
+        .map(|hir_ty| local_alias_chain(cx, hir_ty))
+        .find(|aliases| !aliases.is_empty());
+    if let Some(aliases) = aliases {
+        let names = aliases
+            .iter()
+            .map(|&alias| format!("`{}`", cx.tcx.item_name(alias.to_def_id())))
+            .join(" and ");
+        let spans = aliases.iter().map(|&alias| cx.tcx.def_span(alias)).collect::<Vec<_>>();
+        let plural = if aliases.len() == 1 { "" } else { "es" };
+        diag.span_note(
+            spans,
+            format!("the error type is reached through the type alias{plural} {names}"),
+        );
+    }
+}
+
+/// Points out the `.context(..)` and `.with_context(..)` calls of `anyhow::Context` in the body of
+/// `owner`, which are where the errors are turned into the `anyhow::Error` it returns.
+fn note_anyhow_context_calls(cx: &LateContext<'_>, diag: &mut Diagnostic, owner: LocalDefId) {
+    let Some(body_id) = cx.tcx.hir().maybe_body_owned_by(owner) else {
+        return;
+    };
+    let typeck_results = cx.tcx.typeck(owner);
+    let mut spans = Vec::new();
+    let _: Option<!> = for_each_expr_with_closures(cx, cx.tcx.hir().body(body_id).value, |e| {
+        if let ExprKind::MethodCall(path, ..) = e.kind
+            && !e.span.from_expansion()
+            && let Some(def_id) = typeck_results.type_dependent_def_id(e.hir_id)
+            && (match_def_path(cx, def_id, &paths::ANYHOW_CONTEXT_CONTEXT)
+                || match_def_path(cx, def_id, &paths::ANYHOW_CONTEXT_WITH_CONTEXT))
+        {
+            spans.push(path.ident.span.with_hi(e.span.hi()));
+        }
+        ControlFlow::Continue(())
+    });
+    if !spans.is_empty() {
+        diag.span_note(
+            spans,
+            "the errors are turned into an `anyhow::Error` by `anyhow::Context` here",
+        );
+    }
+}
+
+/// The span to report the error type at: the error type itself if it's written out in `hir_ty`,
+/// e.g. the `String` in `Result<T, String>`, or all of `hir_ty` otherwise, e.g. for aliases of
+/// `Result`.
+fn err_ty_span(hir_ty: &hir::Ty<'_>, written_err_hir_ty: Option<&hir::Ty<'_>>) -> Span {
+    match written_err_hir_ty {
+        Some(err_hir_ty) if !err_hir_ty.span.from_expansion() && hir_ty.span.contains(err_hir_ty.span) => {
+            err_hir_ty.span
+        },
+        _ => hir_ty.span,
+    }
+}
+
+/// Checks if the lint, or `LIBRARY_CRATES_STRUCTURED_ERRORS`, is explicitly allowed on one of the
+/// type aliases of the local crate the error type is reached through, e.g. on
+/// `type MyResult<T> = anyhow::Result<T>`, which covers all the functions returning the alias.
+fn is_allowed_on_alias<'tcx>(
+    cx: &LateContext<'tcx>,
+    lint: &'static Lint,
+    hir_ty: &'tcx hir::Ty<'tcx>,
+    written_err_hir_ty: Option<&'tcx hir::Ty<'tcx>>,
+) -> bool {
+    [Some(hir_ty), written_err_hir_ty]
+        .into_iter()
+        .flatten()
+        .flat_map(|hir_ty| local_alias_chain(cx, hir_ty))
+        .any(|alias| {
+            let hir_id = cx.tcx.local_def_id_to_hir_id(alias);
+            [lint, LIBRARY_CRATES_STRUCTURED_ERRORS].into_iter().any(|lint| {
+                matches!(
+                    cx.tcx.lint_level_at_node(lint, hir_id),
+                    (Level::Allow, source) if !matches!(source, LintLevelSource::Default)
+                )
+            })
+        })
+}
+
+/// Returns the type aliases of the local crate `hir_ty` goes through, from the outermost one, e.g.
+/// `R2` and `R` for `R2<()>` with `type R2<T> = R<T>` and `type R<T> = anyhow::Result<T>`.
+fn local_alias_chain<'tcx>(cx: &LateContext<'tcx>, mut hir_ty: &'tcx hir::Ty<'tcx>) -> Vec<LocalDefId> {
+    let mut aliases = Vec::new();
+    while let hir::TyKind::Path(hir::QPath::Resolved(None, path)) = hir_ty.kind
+        && let Res::Def(DefKind::TyAlias, def_id) = path.res
+        && let Some(def_id) = def_id.as_local()
+        && !aliases.contains(&def_id)
+        && let hir::ItemKind::TyAlias(aliased, _) = cx.tcx.hir().expect_item(def_id).kind
+    {
+        aliases.push(def_id);
+        hir_ty = aliased;
+    }
+    aliases
+}
+
+/// Checks if the type is a path to `anyhow::Result` or `eyre::Result` itself, rather than to an
+/// alias of them.
+fn is_error_crate_result_path(cx: &LateContext<'_>, hir_ty: &hir::Ty<'_>) -> bool {
+    if let hir::TyKind::Path(hir::QPath::Resolved(_, path)) = hir_ty.kind
+        && let Res::Def(DefKind::TyAlias, def_id) = path.res
+    {
+        match_def_path(cx, def_id, &paths::ANYHOW_RESULT) || match_def_path(cx, def_id, &paths::EYRE_RESULT)
+    } else {
+        false
+    }
+}
+
+/// Checks if the type is written as `anyhow::Result<T>` or `eyre::Result<T>`, leaving the error
+/// type implicit. Returns the position to write out the error type at, and the text to insert
+/// there, using the path to `anyhow::Error` or `eyre::Report` relative to the alias' path.
+/// `eyre::Result` defaults `T` to `()` as well, so a bare `eyre::Result` gets both arguments.
+///
+/// The suggestion is only machine applicable if the module the alias is written through exports
+/// the error type as well. An imported alias gets the crate's own name as the prefix, which
+/// doesn't resolve if the dependency is renamed or only reachable through a re-export.
+fn error_crate_result_alias(cx: &LateContext<'_>, hir_ty: &hir::Ty<'_>) -> Option<(Span, String, Applicability)> {
+    if let hir::TyKind::Path(hir::QPath::Resolved(None, path)) = hir_ty.kind
+        && let Res::Def(DefKind::TyAlias, def_id) = path.res
+        && let Some((krate, error_name, error_def_path)) = if match_def_path(cx, def_id, &paths::ANYHOW_RESULT) {
+            Some(("anyhow", "Error", &paths::ANYHOW_ERROR))
+        } else if match_def_path(cx, def_id, &paths::EYRE_RESULT) {
+            Some(("eyre", "Report", &paths::EYRE_REPORT))
+        } else {
+            None
+        }
+        && let [prefix @ .., last] = path.segments
+    {
+        let (error_path, applicability) = if let [.., module] = prefix
+            && let Res::Def(DefKind::Mod, module_def_id) = module.res
+            && let children = if let Some(local_def_id) = module_def_id.as_local() {
+                cx.tcx.module_children_local(local_def_id)
+            } else {
+                cx.tcx.module_children(module_def_id)
+            }
+            && children.iter().any(|child| {
+                child.ident.name.as_str() == error_name
+                    && child
+                        .res
+                        .opt_def_id()
+                        .is_some_and(|child_def_id| match_def_path(cx, child_def_id, error_def_path))
+            }) {
+            let prefix = prefix.iter().map(|segment| segment.ident.as_str()).join("::");
+            (format!("{prefix}::{error_name}"), Applicability::MachineApplicable)
+        } else {
+            (format!("{krate}::{error_name}"), Applicability::MaybeIncorrect)
+        };
+        match last.args {
+            Some(args)
+                if let [ok_arg] = args.args
+                    && args.bindings.is_empty() =>
+            {
+                Some((ok_arg.span().shrink_to_hi(), format!(", {error_path}"), applicability))
+            },
+            None if krate == "eyre" => Some((
+                last.ident.span.shrink_to_hi(),
+                format!("<(), {error_path}>"),
+                applicability,
+            )),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+/// Collects the return types of the `Fn` traits, trait objects and `fn` pointers that appear in a
+/// function signature, e.g. `R` in `impl Fn() -> R`.
+struct FnOutputFinder<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    outputs: Vec<&'tcx hir::Ty<'tcx>>,
+}
+
+impl<'tcx> Visitor<'tcx> for FnOutputFinder<'_, 'tcx> {
+    type NestedFilter = nested_filter::All;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.cx.tcx.hir()
+    }
+
+    fn visit_ty(&mut self, ty: &'tcx hir::Ty<'tcx>) {
+        if let hir::TyKind::BareFn(bare_fn) = ty.kind
+            && let FnRetTy::Return(output) = bare_fn.decl.output
+        {
+            self.outputs.push(output);
+        }
+        walk_ty(self, ty);
+    }
+
+    fn visit_poly_trait_ref(&mut self, poly_trait_ref: &'tcx hir::PolyTraitRef<'tcx>) {
+        if let Some(trait_def_id) = poly_trait_ref.trait_ref.trait_def_id()
+            && self.cx.tcx.fn_trait_kind_from_def_id(trait_def_id).is_some()
+            && let Some(args) = poly_trait_ref
+                .trait_ref
+                .path
+                .segments
+                .last()
+                .and_then(|segment| segment.args)
+            && let [binding] = args.bindings
+            && let hir::TypeBindingKind::Equality {
+                term: hir::Term::Ty(output),
+            } = binding.kind
+        {
+            self.outputs.push(output);
+        }
+        walk_poly_trait_ref(self, poly_trait_ref);
+    }
+}
+
+/// Collects the `Output` of the futures and the `Item` of the streams that appear in the return
+/// type of a function, e.g. `Result<T, E>` in `impl Future<Output = Result<T, E>>`.
+struct AsyncOutputFinder<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    outputs: Vec<&'tcx hir::Ty<'tcx>>,
+}
+
+impl<'tcx> Visitor<'tcx> for AsyncOutputFinder<'_, 'tcx> {
+    type NestedFilter = nested_filter::All;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.cx.tcx.hir()
+    }
+
+    fn visit_poly_trait_ref(&mut self, poly_trait_ref: &'tcx hir::PolyTraitRef<'tcx>) {
+        if let Some(trait_def_id) = poly_trait_ref.trait_ref.trait_def_id()
+            && let Some(output_name) = if self.cx.tcx.lang_items().future_trait() == Some(trait_def_id) {
+                Some(sym::Output)
+            } else if match_def_path(self.cx, trait_def_id, &paths::FUTURES_CORE_STREAM) {
+                Some(sym::Item)
+            } else {
+                None
+            }
+            && let Some(args) = poly_trait_ref
+                .trait_ref
+                .path
+                .segments
+                .last()
+                .and_then(|segment| segment.args)
+        {
+            self.outputs
+                .extend(args.bindings.iter().filter_map(|binding| match binding.kind {
+                    hir::TypeBindingKind::Equality {
+                        term: hir::Term::Ty(output),
+                    } if binding.ident.name == output_name => Some(output),
+                    _ => None,
+                }));
+        }
+        walk_poly_trait_ref(self, poly_trait_ref);
+    }
+}
+
+/// The return type of the function, both as written and normalized. The return type of an
+/// `async fn` is the `Output` of the `impl Future` its signature returns.
+///
+/// With `-Z verbose-internals`, a note is emitted if the return type is written as a path to an
+/// item named `Result`, but doesn't resolve to a `Result`, e.g. for a local type named `Result` or
+/// an `async fn` whose output can't be normalized. The lint doesn't look at these.
+///
+/// Return types with type errors, e.g. an unresolved error type, are skipped.
+fn fn_return_ty<'tcx>(
+    cx: &LateContext<'tcx>,
+    fn_kind: FnKind<'tcx>,
+    decl: &'tcx FnDecl<'tcx>,
+    def_id: LocalDefId,
+) -> Option<(&'tcx hir::Ty<'tcx>, Ty<'tcx>)> {
+    let FnRetTy::Return(hir_ty) = decl.output else {
+        return None;
+    };
+    let param_env = cx.tcx.param_env(def_id);
+    let ty = cx
+        .tcx
+        .instantiate_bound_regions_with_erased(cx.tcx.fn_sig(def_id).instantiate_identity().output());
+    let (written_hir_ty, ret_ty) = if fn_kind.asyncness().is_async() {
+        let output_hir_ty = async_fn_output_hir_ty(cx, hir_ty)?;
+        let output =
+            cx.tcx.lang_items().future_trait().and_then(|future_trait| {
+                make_normalized_projection(cx.tcx, param_env, future_trait, sym::Output, [ty])
+            });
+        (output_hir_ty, output)
+    } else {
+        (
+            hir_ty,
+            Some(cx.tcx.try_normalize_erasing_regions(param_env, ty).unwrap_or(ty)),
+        )
+    };
+    if cx.sess().verbose_internals()
+        && is_written_as_result(cx, written_hir_ty)
+        && !ret_ty.is_some_and(|ret_ty| is_type_diagnostic_item(cx, ret_ty, sym::Result))
+    {
+        let msg = match ret_ty {
+            Some(ret_ty) => format!("found no `Result` in the return type, which resolves to `{ret_ty}`"),
+            None => "found no `Result` in the return type, which couldn't be resolved".to_string(),
+        };
+        cx.tcx.dcx().span_note(written_hir_ty.span, msg);
+    }
+    ret_ty
+        .filter(|ret_ty| !ret_ty.references_error())
+        .map(|ret_ty| (written_hir_ty, ret_ty))
+}
+
+/// Checks if the type is written as a path to an item named `Result`, e.g. `io::Result<T>`. The
+/// name of the item the path resolves to is used, so renamed imports like
+/// `use my_crate::Result as MyResult` are recognized as well.
+fn is_written_as_result(cx: &LateContext<'_>, hir_ty: &hir::Ty<'_>) -> bool {
+    match hir_ty.kind {
+        hir::TyKind::Path(hir::QPath::Resolved(_, path)) => match path.res.opt_def_id() {
+            Some(def_id) => cx.tcx.opt_item_name(def_id) == Some(sym::Result),
+            None => path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident.name == sym::Result),
+        },
+        hir::TyKind::Path(hir::QPath::TypeRelative(_, segment)) => segment.ident.name == sym::Result,
+        _ => false,
+    }
+}
+
+/// Normalizes the type in the `ParamEnv` of `def_id`, resolving projections like
+/// `<Self as Trait>::Error` to the type they stand for. Returns `None` if normalization fails, so
+/// the type is skipped instead. Types with escaping bound regions, like the output of
+/// `for<'a> Fn(&'a str) -> R`, can't be normalized and are returned as is.
+fn normalize_ty<'tcx>(cx: &LateContext<'tcx>, def_id: LocalDefId, ty: Ty<'tcx>) -> Option<Ty<'tcx>> {
+    if ty.has_escaping_bound_vars() {
+        return Some(ty);
+    }
+    cx.tcx.try_normalize_erasing_regions(cx.tcx.param_env(def_id), ty).ok()
+}
+
+/// Gets the return type as written by the user from the `impl Future<Output = T>` an `async fn`'s
+/// return type is lowered to.
+fn async_fn_output_hir_ty<'tcx>(cx: &LateContext<'tcx>, hir_ty: &'tcx hir::Ty<'tcx>) -> Option<&'tcx hir::Ty<'tcx>> {
+    if let hir::TyKind::OpaqueDef(item_id, _, _) = hir_ty.kind
+        && let hir::ItemKind::OpaqueTy(opaque) = &cx.tcx.hir().item(item_id).kind
+        && let [hir::GenericBound::Trait(trait_ref, _)] = opaque.bounds
+        && let Some(args) = trait_ref
+            .trait_ref
+            .path
+            .segments
+            .last()
+            .and_then(|segment| segment.args)
+        && let [binding] = args.bindings
+        && let hir::TypeBindingKind::Equality {
+            term: hir::Term::Ty(output),
+        } = binding.kind
+    {
+        Some(output)
+    } else {
+        None
+    }
+}
+
+/// Returns the hidden type of a local `impl Trait` type, e.g. `String` for the `impl Debug` of a
+/// function returning `Result<(), impl Debug>` that returns `Err(String::new())`.
+fn reveal_opaque_ty<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Ty<'tcx> {
+    if let ty::Alias(ty::Opaque, alias) = ty.kind()
+        && alias.def_id.is_local()
+    {
+        cx.tcx.type_of(alias.def_id).instantiate(cx.tcx, alias.args)
+    } else {
+        ty
+    }
+}
+
+/// Checks if the crate root is in one of the directories directly under the package root that Cargo
+/// looks for examples, benchmarks and integration tests in, either directly, e.g.
+/// `examples/demo.rs`, or as the `main.rs` of a target of its own, e.g. `examples/demo/main.rs`.
+fn is_in_target_dir(source_file: &Path, package_root: &Path) -> bool {
+    let Ok(relative) = source_file.strip_prefix(package_root) else {
+        return false;
+    };
+    let components: Vec<_> = relative.components().map(Component::as_os_str).collect();
+    match *components {
+        [dir, _] => is_target_dir(dir),
+        [dir, _, file] => is_target_dir(dir) && file == "main.rs",
+        _ => false,
+    }
+}
+
+/// Checks if the directory is named like one of the directories Cargo looks for targets in.
+fn is_target_dir(dir: &OsStr) -> bool {
+    ["examples", "benches", "tests"].iter().any(|name| dir == *name)
+}
+
+/// Matches the name against a glob pattern, where `*` matches any number of characters and `?` a
+/// single one.
+fn glob_matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    // The positions in the pattern and the name after the last `*`, to backtrack to
+    let mut star = None;
+    let (mut p, mut n) = (0, 0);
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            },
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            },
+            _ => match star {
+                // Let the `*` match one more character
+                Some((star_p, star_n)) => {
+                    star = Some((star_p, star_n + 1));
+                    p = star_p + 1;
+                    n = star_n + 1;
+                },
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+fn to_crate_type(crate_type: LibraryCrateType) -> CrateType {
+    match crate_type {
+        LibraryCrateType::Rlib => CrateType::Rlib,
+        LibraryCrateType::Dylib => CrateType::Dylib,
+        LibraryCrateType::Cdylib => CrateType::Cdylib,
+        LibraryCrateType::Staticlib => CrateType::Staticlib,
+        LibraryCrateType::ProcMacro => CrateType::ProcMacro,
+    }
+}
+
+fn to_error_category(category: UnstructuredErrorCategory) -> ErrorCategory {
+    match category {
+        UnstructuredErrorCategory::String => ErrorCategory::String,
+        UnstructuredErrorCategory::Boxed => ErrorCategory::Boxed,
+        UnstructuredErrorCategory::Anyhow => ErrorCategory::Anyhow,
+        UnstructuredErrorCategory::Eyre => ErrorCategory::Eyre,
+    }
+}
+
+/// The kinds of unstructured error types that are told apart in diagnostics.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum ErrorCategory {
+    String,
+    Boxed,
+    Anyhow,
+    Eyre,
+    /// Error codes, only reported if `flag_integer_error_types` is enabled.
+    Integer,
+    /// Error types of dependencies, only reported if `flag_foreign_error_types` is enabled.
+    Foreign,
+    /// Type parameters without bounds, only reported if `flag_unbounded_generic_errors` is enabled.
+    UnboundedGeneric,
+    Other,
+}
+
+impl ErrorCategory {
+    const ALL: [Self; 8] = [
+        Self::String,
+        Self::Boxed,
+        Self::Anyhow,
+        Self::Eyre,
+        Self::Integer,
+        Self::Foreign,
+        Self::UnboundedGeneric,
+        Self::Other,
+    ];
+
+    fn of(cx: &LateContext<'_>, err_ty: Ty<'_>) -> Self {
+        if is_string_like_ty(cx, err_ty) {
+            Self::String
+        } else if is_boxed_dyn_error(cx, err_ty) || is_boxed_dyn_any(cx, err_ty) {
+            Self::Boxed
+        } else if is_error_type_at(cx, err_ty, &paths::ANYHOW_ERROR) {
+            Self::Anyhow
+        } else if is_error_type_at(cx, err_ty, &paths::EYRE_REPORT) {
+            Self::Eyre
+        } else {
+            Self::Other
+        }
+    }
+
+    /// The stable name of the category, which matches the names used in the configuration.
+    fn name(self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Boxed => "boxed",
+            Self::Anyhow => "anyhow",
+            Self::Eyre => "eyre",
+            Self::Integer => "integer",
+            Self::Foreign => "foreign",
+            Self::UnboundedGeneric => "unbounded-generic",
+            Self::Other => "other",
+        }
+    }
+
+    /// Whether the category is an error type that can hold any error, which callers can't match
+    /// on without downcasting.
+    fn is_opaque(self) -> bool {
+        matches!(self, Self::Boxed | Self::Anyhow | Self::Eyre)
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::String => "`String`",
+            Self::Boxed => "`Box<dyn Error>`",
+            Self::Anyhow => "`anyhow::Error`",
+            Self::Eyre => "`eyre::Report`",
+            Self::Integer => "integer error codes",
+            Self::Foreign => "error types of dependencies",
+            Self::UnboundedGeneric => "unbounded generic error types",
+            Self::Other => "other unstructured error types",
+        }
+    }
+
+    fn message(self, cx: &LateContext<'_>, err_ty: Ty<'_>) -> String {
+        match self {
+            // `Box<str>`, `Rc<str>`, `Arc<str>` or `Cow<str>`
+            Self::String
+                if let ty::Adt(adt, _) = err_ty.kind()
+                    && !is_type_lang_item(cx, err_ty, LangItem::String) =>
+            {
+                format!("`{}<str>` is an unstructured error type", cx.tcx.item_name(adt.did()))
+            },
+            Self::String => "`String` is an unstructured error type".to_string(),
+            Self::Boxed if is_boxed_dyn_any(cx, err_ty) => "`Box<dyn Any>` is an unstructured error type".to_string(),
+            Self::Boxed => "`Box<dyn Error>` is an unstructured error type".to_string(),
+            Self::Anyhow => "`anyhow::Error` is an unstructured error type".to_string(),
+            Self::Eyre => "`eyre::Report` is an unstructured error type".to_string(),
+            Self::Integer | Self::Other => format!("`{err_ty}` is an unstructured error type"),
+            Self::Foreign => format!("`{err_ty}` is the error type of another crate"),
+            Self::UnboundedGeneric => format!("the error type `{err_ty}` is a type parameter without any bounds"),
+        }
+    }
+}
+
+/// Checks if the type is defined in a dependency of the crate, rather than in the crate itself or
+/// in the standard library.
+fn is_foreign_error_ty(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    if let ty::Adt(adt, _) = ty.kind()
+        && !adt.did().is_local()
+    {
+        !matches!(defining_crate_name(cx, ty), Some(sym::std | sym::core | sym::alloc))
+    } else {
+        false
+    }
+}
+
+/// Checks if the type is an integer error code, or a tuple of them like `(u32, u32)`.
+fn is_integer_code(ty: Ty<'_>) -> bool {
+    match ty.kind() {
+        ty::Tuple(tys) => !tys.is_empty() && tys.iter().all(Ty::is_integral),
+        _ => ty.is_integral(),
+    }
+}
+
+/// Checks if the type parameter has no bounds in the item being checked, apart from the implicit
+/// `Sized` bound and lifetime bounds. Bounds that only mention it, like `anyhow::Error: From<E>`,
+/// count as well.
+fn is_unbounded_param<'tcx>(cx: &LateContext<'tcx>, param_ty: Ty<'tcx>) -> bool {
+    let sized_trait = cx.tcx.lang_items().sized_trait();
+    let mentions_param =
+        |args: &[GenericArg<'tcx>]| args.iter().flat_map(|arg| arg.walk()).any(|arg| arg == param_ty.into());
+    !cx.param_env
+        .caller_bounds()
+        .iter()
+        .any(|clause| match clause.kind().skip_binder() {
+            ty::ClauseKind::Trait(pred) => Some(pred.def_id()) != sized_trait && mentions_param(pred.trait_ref.args),
+            ty::ClauseKind::Projection(pred) => {
+                mentions_param(pred.projection_ty.args) || pred.term.ty().is_some_and(|ty| mentions_param(&[ty.into()]))
+            },
+            _ => false,
+        })
+}
+
+/// Checks if the unstructured error type of the given category is opaque, i.e. a type-erased error
+/// like `Box<dyn Error>` or the catch-all error type of an error handling crate, rather than a
+/// message or an error code.
+fn is_opaque_error_ty(cx: &LateContext<'_>, category: ErrorCategory, ty: Ty<'_>) -> bool {
+    category.is_opaque()
+        || is_error_type_at(cx, ty, &paths::FAILURE_ERROR)
+        || is_error_type_at(cx, ty, &paths::SNAFU_WHATEVER)
+}
+
+/// Checks if the type is the external ADT at the given public path, e.g. `anyhow::Error`.
+fn is_error_type_at(cx: &LateContext<'_>, ty: Ty<'_>, path: &[&str]) -> bool {
+    if let ty::Adt(adt, _) = ty.kind()
+        && !adt.did().is_local()
+        && cx.tcx.crate_name(adt.did().krate).as_str() == path[0]
+    {
+        def_path_def_ids(cx, path).any(|def_id| def_id == adt.did())
+    } else {
+        false
+    }
+}
+
+/// Returns the lint to report the error type with. `String` and boxed errors are reported with
+/// their own lints, falling back to `LIBRARY_CRATES_STRUCTURED_ERRORS` if their level wasn't set.
+/// Returns `None` if the error type's lint was explicitly allowed.
+fn lint_for_category(cx: &LateContext<'_>, category: ErrorCategory, hir_id: HirId) -> Option<&'static Lint> {
+    let category_lint = match category {
+        ErrorCategory::String => LIBRARY_STRING_ERRORS,
+        ErrorCategory::Boxed => LIBRARY_BOXED_ERRORS,
+        ErrorCategory::Anyhow
+        | ErrorCategory::Eyre
+        | ErrorCategory::Integer
+        | ErrorCategory::Foreign
+        | ErrorCategory::UnboundedGeneric
+        | ErrorCategory::Other => {
+            return Some(LIBRARY_CRATES_STRUCTURED_ERRORS);
+        },
+    };
+    match cx.tcx.lint_level_at_node(category_lint, hir_id) {
+        (Level::Allow, LintLevelSource::Default) => Some(LIBRARY_CRATES_STRUCTURED_ERRORS),
+        (Level::Allow, _) => None,
+        _ => Some(category_lint),
+    }
+}
+
+/// Returns the lint to report the error type with, if it's enabled at `hir_id`.
+fn enabled_lint(cx: &LateContext<'_>, category: ErrorCategory, hir_id: HirId) -> Option<&'static Lint> {
+    lint_for_category(cx, category, hir_id).filter(|lint| !is_lint_allowed(cx, lint, hir_id))
+}
+
+/// An unstructured error type in the return type of a method, see `collapse_per_impl`.
+struct PendingMethodLint<'tcx> {
+    method: LocalDefId,
+    hir_ty: &'tcx hir::Ty<'tcx>,
+    written_err_hir_ty: Option<&'tcx hir::Ty<'tcx>>,
+    err_ty: Ty<'tcx>,
+    category: ErrorCategory,
+}
+
+/// Checks if the function is marked with `#[clippy::accepted_unstructured_error]`.
+fn is_accepted_unstructured_error(cx: &LateContext<'_>, def_id: LocalDefId) -> bool {
+    let attrs = cx.tcx.hir().attrs(cx.tcx.local_def_id_to_hir_id(def_id));
+    get_attr(cx.sess(), attrs, "accepted_unstructured_error").count() > 0
+}
+
+/// Checks if the function is marked with `#[clippy::structured_error_todo]`, see
+/// `KNOWN_UNSTRUCTURED_ERRORS`.
+fn is_structured_error_todo(cx: &LateContext<'_>, def_id: LocalDefId) -> bool {
+    let attrs = cx.tcx.hir().attrs(cx.tcx.local_def_id_to_hir_id(def_id));
+    get_attr(cx.sess(), attrs, "structured_error_todo").count() > 0
+}
+
+/// Checks if the type is a local type marked with `#[clippy::structured_error_ok]`.
+fn is_structured_error_ok(cx: &LateContext<'_>, def_id: DefId) -> bool {
+    def_id.as_local().is_some_and(|def_id| {
+        let attrs = cx.tcx.hir().attrs(cx.tcx.local_def_id_to_hir_id(def_id));
+        get_attr(cx.sess(), attrs, "structured_error_ok").count() > 0
+    })
+}
+
+/// Gets the error type as written by the user, if the (nested) `Result` it belongs to is written
+/// out in the return type.
+fn written_err_hir_ty<'tcx>(
+    cx: &LateContext<'_>,
+    hir_ty: &'tcx hir::Ty<'tcx>,
+    is_nested: bool,
+) -> Option<&'tcx hir::Ty<'tcx>> {
+    if !is_nested {
+        return result_err_hir_ty(cx, hir_ty);
+    }
+    if let hir::TyKind::Path(hir::QPath::Resolved(_, path)) = hir_ty.kind
+        && let Some(args) = path.segments.last().and_then(|segment| segment.args)
+        && let Some(hir::GenericArg::Type(inner_ty)) = args.args.first()
+    {
+        result_err_hir_ty(cx, inner_ty)
+    } else {
+        None
+    }
+}
+
+/// Checks if the error type isn't visible in the return type as written, e.g. because it is
+/// returned through an alias of `Result`, or the error type itself is an alias, an associated type,
+/// an `impl Trait` type or was imported under a different name.
+fn is_err_ty_hidden(cx: &LateContext<'_>, written_err_hir_ty: Option<&hir::Ty<'_>>) -> bool {
+    match written_err_hir_ty.map(|err_ty| &err_ty.kind) {
+        Some(hir::TyKind::Path(hir::QPath::Resolved(_, path))) => match path.res {
+            // Includes associated types of generic impls, like `<T as Trait>::Error`
+            Res::Def(DefKind::TyAlias | DefKind::AssocTy, _) => true,
+            Res::Def(_, def_id) => path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident.name != cx.tcx.item_name(def_id)),
+            _ => false,
+        },
+        // `Self::Error`, or an `impl Trait` error type, which is only known from the function's body
+        Some(hir::TyKind::Path(hir::QPath::TypeRelative(..)) | hir::TyKind::OpaqueDef(..)) | None => true,
+        Some(_) => false,
+    }
+}
+
+/// Returns an empty span in front of the item, including its attributes and the attribute macro
+/// it was generated by, if any.
+fn item_insertion_span(cx: &LateContext<'_>, def_id: LocalDefId) -> Span {
+    let hir_id = cx.tcx.local_def_id_to_hir_id(def_id);
+    let span = cx.tcx.hir().span(hir_id);
+    cx.tcx
+        .hir()
+        .attrs(hir_id)
+        .iter()
+        .map(|attr| attr.span)
+        .chain(attr_macro_call_site(cx, def_id))
+        .filter(|attr_span| attr_span.eq_ctxt(span))
+        .fold(
+            span,
+            |span, attr_span| if attr_span.lo() < span.lo() { attr_span } else { span },
+        )
+        .shrink_to_lo()
+}
+
+/// Returns the span of the attribute macro that generated the body of the function, e.g.
+/// `#[tokio::main]`, which wraps the body written by the user in a runtime. The attribute is
+/// consumed by the expansion, so it isn't among the function's attributes anymore, but the
+/// statements the macro added lead back to it.
+fn attr_macro_call_site(cx: &LateContext<'_>, def_id: LocalDefId) -> Option<Span> {
+    let body_id = cx.tcx.hir().maybe_body_owned_by(def_id)?;
+    let ExprKind::Block(block, _) = cx.tcx.hir().body(body_id).value.kind else {
+        return None;
+    };
+    block
+        .stmts
+        .iter()
+        .map(|stmt| stmt.span)
+        .chain(block.expr.map(|expr| expr.span))
+        .map(|span| span.ctxt().outer_expn_data())
+        .find(|expn_data| matches!(expn_data.kind, ExpnKind::Macro(MacroKind::Attr, _)))
+        .map(|expn_data| expn_data.call_site)
+}
+
+/// Checks if the crate root already has an item named `Error`, e.g. an error type or an alias of
+/// one.
+fn crate_root_defines_error(cx: &LateContext<'_>) -> bool {
+    cx.tcx
+        .hir()
+        .root_module()
+        .item_ids
+        .iter()
+        .any(|&id| cx.tcx.hir().item(id).ident.name == sym::Error)
+}
+
+/// The opaque error type as it can be written in the crate root, e.g. `anyhow::Error` or
+/// `Box<dyn std::error::Error + Send + Sync>`. Auto traits are in the prelude, so they aren't
+/// qualified.
+fn error_alias_target(cx: &LateContext<'_>, err_ty: Ty<'_>) -> Option<String> {
+    match err_ty.kind() {
+        ty::Adt(adt, args) if !adt.is_box() => args.is_empty().then(|| cx.tcx.def_path_str(adt.did())),
+        ty::Adt(..) if let ty::Dynamic(predicates, _, _) = err_ty.boxed_ty().kind() => {
+            let principal = cx.tcx.def_path_str(predicates.principal_def_id()?);
+            let auto_traits = predicates
+                .auto_traits()
+                .map(|def_id| format!(" + {}", cx.tcx.item_name(def_id)))
+                .sorted()
+                .collect::<String>();
+            Some(format!("Box<dyn {principal}{auto_traits}>"))
+        },
+        _ => None,
+    }
+}
+
+/// The name of the error type suggested to the user, derived from the crate name.
+fn error_type_name(cx: &LateContext<'_>) -> String {
+    let mut name = to_camel_case(cx.tcx.crate_name(LOCAL_CRATE).as_str());
+    if !name.ends_with("Error") {
+        name.push_str("Error");
+    }
+    name
+}
+
+/// Checks if the crate directly depends on the crate `name`, e.g. on `thiserror`, so its derive
+/// can be suggested. Dependencies that aren't used anywhere in the crate aren't loaded, so they
+/// aren't found.
+fn depends_on_crate(cx: &LateContext<'_>, name: &str) -> bool {
+    cx.tcx.crates(()).iter().any(|&krate| {
+        cx.tcx.crate_name(krate).as_str() == name
+            && cx
+                .tcx
+                .extern_crate(krate.as_def_id())
+                .is_some_and(ExternCrate::is_direct)
+    })
+}
+
+/// An empty error enum, named after the crate, along with the impls needed to make it an error
+/// type. `krate` is the crate the `Display` and `Error` traits are taken from, i.e. `std` or
+/// `core`. If `derive_thiserror` is set, the impls are derived with `thiserror` instead.
+fn error_enum_skeleton(name: &str, krate: &str, derive_thiserror: bool, indent: &str) -> String {
+    if derive_thiserror {
+        return indent_insertion(
+            &format!("#[derive(Debug, thiserror::Error)]\npub enum {name} {{}}"),
+            indent,
+        );
+    }
+    let skeleton = format!(
+        "#[derive(Debug)]
+pub enum {name} {{}}
+
+impl {krate}::fmt::Display for {name} {{
+    fn fmt(&self, f: &mut {krate}::fmt::Formatter<'_>) -> {krate}::fmt::Result {{
+        todo!()
+    }}
+}}
+
+impl {krate}::error::Error for {name} {{}}"
+    );
+    indent_insertion(&skeleton, indent)
+}
+
+/// Indents the item definitions so they can be inserted in front of an item with the given
+/// indentation.
+fn indent_insertion(items: &str, indent: &str) -> String {
+    // the suggestion is inserted after the indentation of the item, so the first line must not be
+    // indented, but the item itself needs to be indented again after the inserted items
+    let items = items
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!("{indent}{line}")
+            }
+        })
+        .join("\n");
+    format!("{}\n\n{indent}", items.trim_start())
+}
+
+impl<'tcx> LateLintPass<'tcx> for LibraryCratesStructuredErrors<'tcx> {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        for path in &self.allowed_error_types {
+            let segs: Vec<_> = path.split("::").collect();
+            self.allowed_def_ids.extend(def_path_def_ids(cx, &segs));
+        }
+        for path in &self.framework_result_types {
+            let segs: Vec<_> = path.split("::").collect();
+            self.framework_result_def_ids.extend(def_path_def_ids(cx, &segs));
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.check_missing_error_impls(cx);
+        if self.flag_inconsistent {
+            self.check_inconsistent_error_types(cx);
+        }
+        if self.emit_count && !self.findings.is_empty() {
+            span_lint(
+                cx,
+                LIBRARY_CRATES_STRUCTURED_ERRORS,
+                DUMMY_SP,
+                &format!("structured-error-violations: {}", self.findings.len()),
+            );
+        }
+        if !self.summarize || self.findings.is_empty() {
+            return;
+        }
+        let fn_count = |category: Option<ErrorCategory>| {
+            self.findings
+                .iter()
+                .filter(|(c, _)| category.map_or(true, |category| *c == category))
+                .map(|(_, def_id)| def_id)
+                .unique()
+                .count()
+        };
+        let functions = |count: usize| if count == 1 { "function" } else { "functions" };
+        let total = fn_count(None);
+        // Only the exported API is checked by default, with a wider visibility the functions may be
+        // private
+        let kind = match self.visibility {
+            StructuredErrorVisibility::Public => "exported ",
+            StructuredErrorVisibility::Crate | StructuredErrorVisibility::All => "",
+        };
+        let verb = if total == 1 { "uses" } else { "use" };
+        span_lint_and_then(
+            cx,
+            LIBRARY_CRATES_STRUCTURED_ERRORS,
+            DUMMY_SP,
+            &format!("{total} {kind}{} {verb} unstructured error types", functions(total)),
+            |diag| {
+                for category in ErrorCategory::ALL {
+                    let count = fn_count(Some(category));
+                    if count > 0 {
+                        diag.note(format!("{}: {count} {}", category.description(), functions(count)));
+                    }
+                }
+            },
+        );
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        if let hir::ItemKind::Struct(..) | hir::ItemKind::Enum(..) = item.kind
+            && item.ident.as_str().ends_with("Error")
+            && !self.skip_item(cx, item.owner_id.def_id, item.span)
+        {
+            self.error_types.entry(item.owner_id.def_id).or_insert(None);
+        }
+        if let hir::ItemKind::Enum(def, _) = item.kind
+            && !self.skip_item(cx, item.owner_id.def_id, item.span)
+            && implements_error_trait(cx, cx.tcx.type_of(item.owner_id).instantiate_identity())
+            && !is_structured_error_ok(cx, item.owner_id.to_def_id())
+        {
+            check_catch_all_variants(cx, self.message_style, item.ident, &def);
+        }
+        if self.flag_opaque_wrapper_errors {
+            self.check_opaque_wrapper(cx, item);
+        }
+        self.check_error_fields(cx, item);
+        if let hir::ItemKind::Impl(impl_) = item.kind
+            && impl_.of_trait.is_some()
+            && let Some(trait_ref) = cx.tcx.impl_trait_ref(item.owner_id)
+            && let trait_ref = trait_ref.instantiate_identity()
+            && cx.tcx.is_diagnostic_item(sym::From, trait_ref.def_id)
+            && let source_ty = trait_ref.args.type_at(1)
+            && let Some(source) = if is_type_lang_item(cx, source_ty, LangItem::String) {
+                Some("String")
+            } else if let ty::Ref(_, ty, _) = source_ty.kind()
+                && ty.is_str()
+            {
+                Some("&str")
+            } else {
+                None
+            }
+            && let self_ty = trait_ref.self_ty()
+            && let ty::Adt(adt, _) = self_ty.kind()
+            && let Some(local_def_id) = adt.did().as_local()
+            && !self.skip_item(cx, local_def_id, item.span)
+            && implements_error_trait(cx, self_ty)
+        {
+            span_lint_and_then(
+                cx,
+                ERROR_FROM_STRING,
+                cx.tcx.def_span(item.owner_id),
+                &format!("`{self_ty}` can be created from any `{source}`"),
+                |diag| {
+                    add_advice(
+                        diag,
+                        self.message_style,
+                        format!("consider adding a variant with typed fields to `{self_ty}` for each case instead"),
+                    );
+                },
+            );
+        }
+        // `where Self::Error: Into<anyhow::Error>` on the trait itself
+        if let hir::ItemKind::Trait(_, _, generics, _, _) = item.kind
+            && !self.skip_item(cx, item.owner_id.def_id, item.span)
+        {
+            for predicate in generics.predicates {
+                if let hir::WherePredicate::BoundPredicate(predicate) = predicate
+                    && let ty::Alias(ty::Projection, _) = hir_ty_to_ty(cx.tcx, predicate.bounded_ty).kind()
+                {
+                    self.check_bounds(cx, item.owner_id.def_id, item.owner_id.def_id, predicate.bounds);
+                }
+            }
+        }
+    }
+
+    fn check_item_post(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        let Some(pending) = self.pending_impl_lints.remove(&item.owner_id.def_id) else {
+            return;
+        };
+        let mut by_err_ty: FxIndexMap<Ty<'tcx>, Vec<PendingMethodLint<'tcx>>> = FxIndexMap::default();
+        for pending in pending {
+            by_err_ty.entry(pending.err_ty).or_default().push(pending);
+        }
+        for (err_ty, methods) in by_err_ty {
+            if let [method] = &methods[..] {
+                if let Some(category) = emit_lint(
+                    cx,
+                    self.message_style,
+                    method.method,
+                    item.owner_id.def_id,
+                    method.hir_ty,
+                    method.written_err_hir_ty,
+                    err_ty,
+                    method.category,
+                ) {
+                    self.record_finding(cx, category, err_ty, method.method);
+                }
+                continue;
+            }
+            let category = methods[0].category;
+            let Some(lint) = enabled_lint(cx, category, item.hir_id()) else {
+                continue;
+            };
+            span_lint_hir_and_then(
+                cx,
+                lint,
+                item.hir_id(),
+                cx.tcx.def_span(item.owner_id),
+                &format!(
+                    "{} methods in this `impl` use the unstructured error type `{err_ty}`",
+                    methods.len()
+                ),
+                |diag| {
+                    note_category(diag, category);
+                    for method in &methods {
+                        diag.span_label(err_ty_span(method.hir_ty, method.written_err_hir_ty), "");
+                    }
+                    add_advice(
+                        diag,
+                        self.message_style,
+                        "consider defining an error enum for the methods of this `impl`",
+                    );
+                    add_advice(diag, self.message_style, NON_EXHAUSTIVE_HELP);
+                },
+            );
+            for method in &methods {
+                self.record_finding(cx, category, err_ty, method.method);
+            }
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::ImplItem<'tcx>) {
+        // `type Output = anyhow::Result<T>` in trait impls of exported types, e.g. of `Index`
+        if let hir::ImplItemKind::Type(hir_ty) = item.kind
+            && item.ident.name == sym::Output
+            && let impl_def_id = cx.tcx.local_parent(item.owner_id.def_id)
+            && cx.tcx.impl_trait_ref(impl_def_id).is_some()
+            && let ty::Adt(adt, _) = cx.tcx.type_of(impl_def_id).instantiate_identity().kind()
+            && let Some(self_def_id) = adt.did().as_local()
+            && !self.skip_item(cx, self_def_id, item.span)
+            && let Some(ty) = normalize_ty(cx, impl_def_id, cx.tcx.type_of(item.owner_id).instantiate_identity())
+            && let Some((err_ty, category, is_nested)) = self.find_unstructured_err_ty(cx, ty)
+        {
+            emit_lint(
+                cx,
+                self.message_style,
+                item.owner_id.def_id,
+                impl_def_id,
+                hir_ty,
+                written_err_hir_ty(cx, hir_ty, is_nested),
+                err_ty,
+                category,
+            );
+        }
+        // `type Error = anyhow::Error` in `TryFrom` and `TryInto` impls of exported types
+        if let hir::ImplItemKind::Type(hir_ty) = item.kind
+            && item.ident.name == sym::Error
+            && let impl_def_id = cx.tcx.local_parent(item.owner_id.def_id)
+            && let Some(trait_ref) = cx.tcx.impl_trait_ref(impl_def_id)
+            && let trait_ref = trait_ref.instantiate_identity()
+            && matches!(
+                cx.tcx.get_diagnostic_name(trait_ref.def_id),
+                Some(sym::TryFrom | sym::TryInto)
+            )
+            && let ty::Adt(adt, _) = trait_ref.self_ty().kind()
+            && let Some(self_def_id) = adt.did().as_local()
+            && !self.skip_item(cx, self_def_id, item.span)
+        {
+            let ty = cx.tcx.type_of(item.owner_id).instantiate_identity();
+            if let Some(ty) = normalize_ty(cx, impl_def_id, ty)
+                && let Some(category) = self.is_overly_generic_error_type(cx, ty)
+            {
+                emit_lint(
+                    cx,
+                    self.message_style,
+                    item.owner_id.def_id,
+                    impl_def_id,
+                    hir_ty,
+                    Some(hir_ty),
+                    ty,
+                    category,
+                );
+            }
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::TraitItem<'tcx>) {
+        // Methods with a default body are checked by `check_fn`
+        if let hir::TraitItemKind::Fn(ref sig, hir::TraitFn::Required(_)) = item.kind
+            && !self.skip_item(cx, item.owner_id.def_id, item.span)
+            && !is_accepted_unstructured_error(cx, item.owner_id.def_id)
+            && let Some((hir_ty, ret_ty)) =
+                fn_return_ty(cx, FnKind::Method(item.ident, sig), sig.decl, item.owner_id.def_id)
+            && !self.is_framework_result(hir_ty, ret_ty)
+        {
+            // `#[async_trait]` methods return a `Pin<Box<dyn Future<Output = ..>>>`, which is
+            // reported where its `Output` is written
+            let mut async_finder = AsyncOutputFinder {
+                cx,
+                outputs: Vec::new(),
+            };
+            async_finder.visit_ty(hir_ty);
+            let found: Vec<_> = if async_finder.outputs.is_empty() {
+                self.find_unstructured_err_ty(cx, ret_ty)
+                    .map(|(err_ty, category, is_nested)| {
+                        (hir_ty, written_err_hir_ty(cx, hir_ty, is_nested), err_ty, category)
+                    })
+                    .into_iter()
+                    .collect()
+            } else {
+                async_finder
+                    .outputs
+                    .into_iter()
+                    .filter_map(|output| {
+                        let ty = normalize_ty(cx, item.owner_id.def_id, hir_ty_to_ty(cx.tcx, output))?;
+                        let (err_ty, category, is_nested) = self.find_unstructured_err_ty(cx, ty)?;
+                        Some((output, written_err_hir_ty(cx, output, is_nested), err_ty, category))
+                    })
+                    .collect()
+            };
+            for (hir_ty, written_err_hir_ty, err_ty, category) in found {
+                if let Some(category) = emit_lint(
+                    cx,
+                    self.message_style,
+                    item.owner_id.def_id,
+                    cx.tcx.local_parent(item.owner_id.def_id),
+                    hir_ty,
+                    written_err_hir_ty,
+                    err_ty,
+                    category,
+                ) {
+                    self.record_finding(cx, category, err_ty, item.owner_id.def_id);
+                }
+            }
+        }
+        if let hir::TraitItemKind::Type(bounds, default) = item.kind
+            && !self.skip_item(cx, item.owner_id.def_id, item.span)
+        {
+            let trait_def_id = cx.tcx.local_parent(item.owner_id.def_id);
+            if let Some(default) = default {
+                let ty = cx.tcx.type_of(item.owner_id).instantiate_identity();
+                // `type Output = anyhow::Result<T>` is a result rather than an error type
+                let found = normalize_ty(cx, trait_def_id, ty).and_then(|ty| {
+                    if item.ident.name == sym::Output
+                        && let Some((err_ty, category, is_nested)) = self.find_unstructured_err_ty(cx, ty)
+                    {
+                        Some((err_ty, category, written_err_hir_ty(cx, default, is_nested)))
+                    } else {
+                        self.is_overly_generic_error_type(cx, ty)
+                            .map(|category| (ty, category, Some(default)))
+                    }
+                });
+                if let Some((err_ty, category, written_err_hir_ty)) = found {
+                    emit_lint(
+                        cx,
+                        self.message_style,
+                        item.owner_id.def_id,
+                        trait_def_id,
+                        default,
+                        written_err_hir_ty,
+                        err_ty,
+                        category,
+                    );
+                }
+            }
+            self.check_bounds(cx, item.owner_id.def_id, trait_def_id, bounds);
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) {
+        if let Some(span) = stringified_error_span(cx, e)
+            && let Some(handler) = stringified_error_handler(cx, e)
+            && let owner = cx.tcx.hir().get_parent_item(e.hir_id).def_id
+            && matches!(cx.tcx.def_kind(owner), DefKind::Fn | DefKind::AssocFn)
+            && !self.skip_item(cx, owner, e.span)
+            && !is_in_test_function(cx.tcx, e.hir_id)
+            && !is_in_cfg_test(cx.tcx, e.hir_id)
+        {
+            span_lint_and_then(
+                cx,
+                STRINGIFIED_ERROR_HANDLING,
+                span.with_hi(handler.span.hi()),
+                "the error is turned into a `String` before it's handled",
+                |diag| {
+                    add_advice(
+                        diag,
+                        self.message_style,
+                        "consider handling the original error, and only formatting it where the message is needed",
+                    );
+                },
+            );
+        }
+        self.check_opaque_propagation(cx, e);
+    }
+
+    #[expect(clippy::too_many_lines)]
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        fn_kind: FnKind<'tcx>,
+        fn_: &'tcx FnDecl<'tcx>,
+        body: &'tcx Body<'tcx>,
+        span: Span,
+        local_def_id: LocalDefId,
+    ) {
+        if !matches!(fn_kind, FnKind::Method(..) | FnKind::ItemFn(..))
+            || self.skip_item(cx, local_def_id, span)
+            || is_accepted_unstructured_error(cx, local_def_id)
+            // `main` is reported by `main_uses_boxed_error`, if anything
+            || is_entrypoint_fn(cx, local_def_id.to_def_id())
+            || self.is_dictated_by_trait(cx, local_def_id)
+            // Tests aren't part of the API, even if they're reachable
+            || cx.tcx.has_attr(local_def_id, sym::test)
+            || is_in_test_function(cx.tcx, body.value.hir_id)
+            || is_in_cfg_test(cx.tcx, body.value.hir_id)
+        {
+            return;
+        }
+        // Suggested error types go in front of the function, or the `impl`/`trait` block of methods
+        let suggestion_item = if let FnKind::Method(..) = fn_kind {
+            cx.tcx.local_parent(local_def_id)
+        } else {
+            local_def_id
+        };
+        // Callbacks taken or returned by the function are part of its signature as well, and so are
+        // the results of the futures and streams it returns
+        let mut finder = FnOutputFinder {
+            cx,
+            outputs: Vec::new(),
+        };
+        let mut async_finder = AsyncOutputFinder {
+            cx,
+            outputs: Vec::new(),
+        };
+        let ret = fn_return_ty(cx, fn_kind, fn_, local_def_id);
+        if ret.is_some_and(|(hir_ty, ret_ty)| self.is_framework_result(hir_ty, ret_ty)) {
+            return;
+        }
+        if let Some((hir_ty, _)) = ret {
+            async_finder.visit_ty(hir_ty);
+        }
+        //We are looking for functions that return anyhow::Result or
+        // Result<_, Box<dyn Error>> or Result<_, String>
+        // `Self::Output` is reported where the `Output` of the impl is defined
+        if let Some((hir_ty, ret_ty)) = ret
+            && !is_self_output(hir_ty)
+        {
+            if let Some((err_ty, category, written_err_hir_ty)) =
+                self.find_return_err_ty(cx, hir_ty, ret_ty, body, !async_finder.outputs.is_empty())
+            {
+                if self.collapse_per_impl
+                    && !is_structured_error_todo(cx, local_def_id)
+                    && let FnKind::Method(..) = fn_kind
+                    && let DefKind::Impl { .. } = cx.tcx.def_kind(suggestion_item)
+                {
+                    if let Some(lint) = enabled_lint(cx, category, cx.tcx.local_def_id_to_hir_id(local_def_id))
+                        && !is_allowed_on_alias(cx, lint, hir_ty, written_err_hir_ty)
+                    {
+                        self.pending_impl_lints
+                            .entry(suggestion_item)
+                            .or_default()
+                            .push(PendingMethodLint {
+                                method: local_def_id,
+                                hir_ty,
+                                written_err_hir_ty,
+                                err_ty,
+                                category,
+                            });
+                    }
+                } else if let Some(category) = emit_lint(
+                    cx,
+                    self.message_style,
+                    local_def_id,
+                    suggestion_item,
+                    hir_ty,
+                    written_err_hir_ty,
+                    err_ty,
+                    category,
+                ) {
+                    self.record_finding(cx, category, err_ty, local_def_id);
+                }
+            } else {
+                self.check_compound_err_ty(cx, local_def_id, suggestion_item, hir_ty, ret_ty);
+            }
+        }
+        for input in fn_.inputs {
+            finder.visit_ty(input);
+        }
+        if let FnRetTy::Return(hir_ty) = fn_.output {
+            finder.visit_ty(hir_ty);
+        }
+        if let Some(generics) = cx.tcx.hir().get_generics(local_def_id) {
+            finder.visit_generics(generics);
+        }
+        for output in finder.outputs.into_iter().chain(async_finder.outputs) {
+            if let Some(ty) = normalize_ty(cx, local_def_id, hir_ty_to_ty(cx.tcx, output))
+                && let Some((err_ty, category, is_nested)) = self.find_unstructured_err_ty(cx, ty)
+                && let Some(category) = emit_lint(
+                    cx,
+                    self.message_style,
+                    local_def_id,
+                    suggestion_item,
+                    output,
+                    written_err_hir_ty(cx, output, is_nested),
+                    err_ty,
+                    category,
+                )
+            {
+                self.record_finding(cx, category, err_ty, local_def_id);
+            }
+        }
+        if let Some((hir_ty, ret_ty)) = ret {
+            self.check_error_param_bounds(cx, local_def_id, suggestion_item, ret_ty);
+            self.record_error_type(cx, hir_ty, ret_ty);
+        }
+        self.check_param_defaults(cx, local_def_id, suggestion_item);
+        // The error types of marked functions are reported by `known_unstructured_errors`, there's
+        // nothing to add about their parameters and bodies
+        if is_structured_error_todo(cx, local_def_id) {
+            return;
+        }
+        self.check_params(cx, local_def_id, fn_);
+        if !self.is_exempt(ErrorCategory::String) {
+            check_stringified_errors(cx, self.message_style, local_def_id, body);
+        }
+    }
+}
+
+/// Reports the variants of the error enum that are named like a catch-all and only wrap a string,
+/// see `ERROR_ENUM_STRINGLY_CATCHALL`.
+fn check_catch_all_variants(
+    cx: &LateContext<'_>,
+    message_style: StructuredErrorMessageStyle,
+    enum_ident: Ident,
+    def: &hir::EnumDef<'_>,
+) {
+    for variant in def.variants {
+        if CATCH_ALL_VARIANTS.contains(&variant.ident.as_str())
+            && let [field] = variant.data.fields()
+            && is_string_like_ty(cx, cx.tcx.type_of(field.def_id).instantiate_identity())
+            && !variant.span.from_expansion()
+        {
+            span_lint_and_then(
+                cx,
+                ERROR_ENUM_STRINGLY_CATCHALL,
+                variant.span,
+                &format!(
+                    "`{}` is a catch-all variant of the error enum `{enum_ident}` that only wraps a string",
+                    variant.ident
+                ),
+                |diag| {
+                    add_advice(
+                        diag,
+                        message_style,
+                        "consider replacing it with variants with typed fields for the errors it's used for",
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// Errors might also be turned into strings in the body, even if the error type is structured,
+/// e.g. with `map_err(|e| e.to_string())?` and a `From<String>` impl.
+fn check_stringified_errors<'tcx>(
+    cx: &LateContext<'tcx>,
+    message_style: StructuredErrorMessageStyle,
+    def_id: LocalDefId,
+    body: &'tcx Body<'tcx>,
+) {
+    let hir_id = cx.tcx.local_def_id_to_hir_id(def_id);
+    let Some(lint) = lint_for_category(cx, ErrorCategory::String, hir_id) else {
+        return;
+    };
+    let _: Option<!> = for_each_expr_with_closures(cx, body.value, |e| {
+        if let Some(span) = stringified_error_span(cx, e)
+            // Reported by `stringified_error_handling`
+            && stringified_error_handler(cx, e).is_none()
+        {
+            span_lint_hir_and_then(
+                cx,
+                lint,
+                hir_id,
+                span,
+                "this turns the error into an unstructured `String`",
+                |diag| {
+                    note_category(diag, ErrorCategory::String);
+                    add_advice(
+                        diag,
+                        message_style,
+                        "consider wrapping the original error in a variant of an error enum",
+                    );
+                },
+            );
+        }
+        ControlFlow::Continue(())
+    });
+}
+
+/// Returns the span of `map_err(..)` if the expression is a `map_err` call on a `Result` whose
+/// closure turns the error into a `String` with `to_string()` or `format!`.
+fn stringified_error_span(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<Span> {
+    if let ExprKind::MethodCall(path, receiver, [arg], _) = e.kind
+        && path.ident.as_str() == "map_err"
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(receiver), sym::Result)
+        && let ExprKind::Closure(closure) = arg.kind
+        && let closure_value = peel_blocks(cx.tcx.hir().body(closure.body).value)
+        && is_type_lang_item(cx, cx.typeck_results().expr_ty(closure_value), LangItem::String)
+        && (matches!(closure_value.kind, ExprKind::MethodCall(path, _, [], _) if path.ident.name == sym::to_string)
+            || root_macro_call_first_node(cx, closure_value)
+                .is_some_and(|macro_call| cx.tcx.is_diagnostic_item(sym::format_macro, macro_call.def_id)))
+    {
+        Some(path.ident.span.with_hi(e.span.hi()))
+    } else {
+        None
+    }
+}
+
+/// Returns the method call that handles the stringified error of a `map_err(..)` call right away,
+/// e.g. `unwrap_or_else(..)`, rather than propagating it in another `Result`.
+fn stringified_error_handler<'tcx>(cx: &LateContext<'tcx>, e: &Expr<'_>) -> Option<&'tcx Expr<'tcx>> {
+    if let Some(parent) = get_parent_expr(cx, e)
+        && let ExprKind::MethodCall(_, receiver, ..) = parent.kind
+        && receiver.hir_id == e.hir_id
+        && !is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(parent), sym::Result)
+    {
+        Some(parent)
+    } else {
+        None
+    }
+}
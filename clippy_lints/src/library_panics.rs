@@ -0,0 +1,122 @@
+use crate::library_crates_structured_errors::{LibraryScope, StructuredErrorsConf};
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::macros::{is_panic, root_macro_call_first_node};
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::{for_each_expr_with_closures, Descend};
+use clippy_utils::{is_in_cfg_test, is_in_test_function};
+use core::ops::ControlFlow;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, Expr, ExprKind, FnDecl};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::impl_lint_pass;
+use rustc_span::def_id::LocalDefId;
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Finds `panic!`, `unreachable!`, `unwrap` and `expect` in the exported functions of library
+    /// crates.
+    ///
+    /// ### Why is this bad?
+    /// Users of the library can't recover from a panic, whereas they can handle an error returned
+    /// by the function in whatever way is appropriate for them.
+    ///
+    /// ### Known problems
+    /// Only panics in the body of the exported function itself are found, not the ones in the
+    /// functions it calls.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// pub fn first_word(text: &str) -> &str {
+    ///     text.split_whitespace().next().unwrap()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// #[derive(Debug)]
+    /// pub struct EmptyText;
+    ///
+    /// pub fn first_word(text: &str) -> Result<&str, EmptyText> {
+    ///     text.split_whitespace().next().ok_or(EmptyText)
+    /// }
+    /// ```
+    #[clippy::version = "1.77.0"]
+    pub LIBRARY_PANICS,
+    restriction,
+    "exported functions of library crates that panic"
+}
+
+pub struct LibraryPanics {
+    scope: LibraryScope,
+}
+
+impl LibraryPanics {
+    pub fn new(conf: &StructuredErrorsConf) -> Self {
+        Self {
+            scope: LibraryScope::new(conf),
+        }
+    }
+}
+
+impl_lint_pass!(LibraryPanics => [LIBRARY_PANICS]);
+
+impl<'tcx> LateLintPass<'tcx> for LibraryPanics {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        fn_kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'tcx>,
+        body: &'tcx Body<'tcx>,
+        span: Span,
+        local_def_id: LocalDefId,
+    ) {
+        if !matches!(fn_kind, FnKind::Method(..) | FnKind::ItemFn(..))
+            || !cx.effective_visibilities.is_exported(local_def_id)
+            || in_external_macro(cx.sess(), span)
+            || !self.scope.is_library_crate(cx)
+            || self.scope.is_generated_or_hidden(cx, local_def_id, span)
+            // Tests aren't part of the API, even if they're reachable
+            || cx.tcx.has_attr(local_def_id, sym::test)
+            || is_in_test_function(cx.tcx, body.value.hir_id)
+            || is_in_cfg_test(cx.tcx, body.value.hir_id)
+        {
+            return;
+        }
+        let _: Option<!> = for_each_expr_with_closures(cx, body.value, |e| {
+            if let Some(panic_span) = panic_span(cx, e) {
+                span_lint_and_help(
+                    cx,
+                    LIBRARY_PANICS,
+                    panic_span,
+                    "this can panic in an exported function of a library crate",
+                    None,
+                    "consider returning a `Result` instead",
+                );
+                ControlFlow::Continue(Descend::No)
+            } else {
+                ControlFlow::Continue(Descend::Yes)
+            }
+        });
+    }
+}
+
+/// Returns the span of the `panic!`/`unreachable!` call or the `unwrap`/`expect` method call the
+/// expression is, if any.
+fn panic_span(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<Span> {
+    if let Some(macro_call) = root_macro_call_first_node(cx, e) {
+        return (is_panic(cx, macro_call.def_id)
+            || cx.tcx.is_diagnostic_item(sym::unreachable_macro, macro_call.def_id))
+        .then_some(macro_call.span);
+    }
+    if let ExprKind::MethodCall(path, receiver, _, _) = e.kind
+        && matches!(path.ident.name, sym::unwrap | sym::expect)
+        && let receiver_ty = cx.typeck_results().expr_ty(receiver).peel_refs()
+        && (is_type_diagnostic_item(cx, receiver_ty, sym::Option)
+            || is_type_diagnostic_item(cx, receiver_ty, sym::Result))
+    {
+        Some(path.ident.span.to(e.span.shrink_to_hi()))
+    } else {
+        None
+    }
+}
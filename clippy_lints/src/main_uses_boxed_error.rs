@@ -0,0 +1,80 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::is_entrypoint_fn;
+use clippy_utils::ty::{is_boxed_dyn_error, result_err_hir_ty, result_err_ty};
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, FnDecl};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::config::CrateType;
+use rustc_session::impl_lint_pass;
+use rustc_span::def_id::LocalDefId;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for the `main` function of a binary crate returning a `Box<dyn Error>`.
+    ///
+    /// ### Why is this bad?
+    /// A boxed error erases what went wrong. A named error enum documents every way the
+    /// program can fail, and lets the program decide how to report each of them.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use std::error::Error;
+    /// fn main() -> Result<(), Box<dyn Error>> {
+    ///     todo!()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// #[derive(Debug)]
+    /// enum AppError {
+    ///     Io(std::io::Error),
+    /// }
+    /// fn main() -> Result<(), AppError> {
+    ///     todo!()
+    /// }
+    /// ```
+    #[clippy::version = "1.77.0"]
+    pub MAIN_USES_BOXED_ERROR,
+    restriction,
+    "binary crates with a `main` function returning a `Box<dyn Error>`"
+}
+
+#[derive(Default)]
+pub struct MainUsesBoxedError {
+    is_bin_crate: bool,
+}
+
+impl_lint_pass!(MainUsesBoxedError => [MAIN_USES_BOXED_ERROR]);
+
+impl<'tcx> LateLintPass<'tcx> for MainUsesBoxedError {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        self.is_bin_crate = cx.tcx.crate_types().contains(&CrateType::Executable);
+    }
+
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        fn_kind: FnKind<'tcx>,
+        fn_: &'tcx FnDecl<'tcx>,
+        _: &'tcx Body<'tcx>,
+        span: Span,
+        local_def_id: LocalDefId,
+    ) {
+        if self.is_bin_crate
+            && let FnKind::ItemFn(..) = fn_kind
+            && is_entrypoint_fn(cx, local_def_id.to_def_id())
+            && let Some((hir_ty, err_ty)) = result_err_ty(cx, fn_, local_def_id, span)
+            && is_boxed_dyn_error(cx, err_ty)
+        {
+            span_lint_and_help(
+                cx,
+                MAIN_USES_BOXED_ERROR,
+                result_err_hir_ty(cx, hir_ty).map_or(hir_ty.span, |err_ty| err_ty.span),
+                "`main` returns an unstructured boxed error",
+                None,
+                "consider using a named error enum",
+            );
+        }
+    }
+}
@@ -23,6 +23,9 @@ pub const BUILTIN_ATTRIBUTES: &[(&str, DeprecationStatus)] = &[
     ("dump",                  DeprecationStatus::None),
     ("msrv",                  DeprecationStatus::None),
     ("has_significant_drop",  DeprecationStatus::None),
+    ("accepted_unstructured_error", DeprecationStatus::None),
+    ("structured_error_todo", DeprecationStatus::None),
+    ("structured_error_ok",   DeprecationStatus::None),
 ];
 
 pub struct LimitStack {
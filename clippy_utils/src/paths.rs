@@ -4,6 +4,14 @@
 //! Whenever possible, please consider diagnostic items over hardcoded paths.
 //! See <https://github.com/rust-lang/rust-clippy/issues/5393> for more information.
 
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const ANYHOW_ERROR: [&str; 2] = ["anyhow", "Error"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const ANYHOW_CONTEXT_CONTEXT: [&str; 3] = ["anyhow", "Context", "context"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const ANYHOW_CONTEXT_WITH_CONTEXT: [&str; 3] = ["anyhow", "Context", "with_context"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const ANYHOW_RESULT: [&str; 2] = ["anyhow", "Result"];
 pub const APPLICABILITY: [&str; 2] = ["rustc_lint_defs", "Applicability"];
 pub const APPLICABILITY_VALUES: [[&str; 3]; 4] = [
     ["rustc_lint_defs", "Applicability", "Unspecified"],
@@ -17,6 +25,7 @@ pub const BTREEMAP_CONTAINS_KEY: [&str; 6] = ["alloc", "collections", "btree", "
 pub const BTREEMAP_INSERT: [&str; 6] = ["alloc", "collections", "btree", "map", "BTreeMap", "insert"];
 pub const BTREESET_ITER: [&str; 6] = ["alloc", "collections", "btree", "set", "BTreeSet", "iter"];
 pub const CLONE_TRAIT_METHOD: [&str; 4] = ["core", "clone", "Clone", "clone"];
+pub const CORE_CONVERT_INFALLIBLE: [&str; 3] = ["core", "convert", "Infallible"];
 pub const CORE_ITER_CLONED: [&str; 6] = ["core", "iter", "traits", "iterator", "Iterator", "cloned"];
 pub const CORE_ITER_COPIED: [&str; 6] = ["core", "iter", "traits", "iterator", "Iterator", "copied"];
 pub const CORE_ITER_FILTER: [&str; 6] = ["core", "iter", "traits", "iterator", "Iterator", "filter"];
@@ -24,10 +33,18 @@ pub const CORE_RESULT_OK_METHOD: [&str; 4] = ["core", "result", "Result", "ok"];
 pub const CSTRING_AS_C_STR: [&str; 5] = ["alloc", "ffi", "c_str", "CString", "as_c_str"];
 pub const EARLY_CONTEXT: [&str; 2] = ["rustc_lint", "EarlyContext"];
 pub const EARLY_LINT_PASS: [&str; 3] = ["rustc_lint", "passes", "EarlyLintPass"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const EYRE_REPORT: [&str; 2] = ["eyre", "Report"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const EYRE_RESULT: [&str; 2] = ["eyre", "Result"];
 pub const F32_EPSILON: [&str; 4] = ["core", "f32", "<impl f32>", "EPSILON"];
 pub const F64_EPSILON: [&str; 4] = ["core", "f64", "<impl f64>", "EPSILON"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const FAILURE_ERROR: [&str; 2] = ["failure", "Error"];
 pub const FILE_OPTIONS: [&str; 4] = ["std", "fs", "File", "options"];
 #[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const FUTURES_CORE_STREAM: [&str; 3] = ["futures_core", "stream", "Stream"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
 pub const FUTURES_IO_ASYNCREADEXT: [&str; 3] = ["futures_util", "io", "AsyncReadExt"];
 #[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
 pub const FUTURES_IO_ASYNCWRITEEXT: [&str; 3] = ["futures_util", "io", "AsyncWriteExt"];
@@ -74,6 +91,8 @@ pub const SERDE_DE_VISITOR: [&str; 3] = ["serde", "de", "Visitor"];
 pub const SLICE_GET: [&str; 4] = ["core", "slice", "<impl [T]>", "get"];
 pub const SLICE_INTO_VEC: [&str; 4] = ["alloc", "slice", "<impl [T]>", "into_vec"];
 pub const SLICE_INTO: [&str; 4] = ["core", "slice", "<impl [T]>", "iter"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const SNAFU_WHATEVER: [&str; 2] = ["snafu", "Whatever"];
 pub const STD_IO_SEEK_FROM_CURRENT: [&str; 4] = ["std", "io", "SeekFrom", "Current"];
 pub const STD_IO_SEEKFROM_START: [&str; 4] = ["std", "io", "SeekFrom", "Start"];
 pub const STRING_AS_MUT_STR: [&str; 4] = ["alloc", "string", "String", "as_mut_str"];
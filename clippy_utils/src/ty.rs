@@ -9,7 +9,7 @@ use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_hir as hir;
 use rustc_hir::def::{CtorKind, CtorOf, DefKind, Res};
 use rustc_hir::def_id::DefId;
-use rustc_hir::{Expr, FnDecl, LangItem, TyKind, Unsafety};
+use rustc_hir::{Expr, ExprKind, FnDecl, LangItem, MatchSource, TyKind, Unsafety};
 use rustc_infer::infer::type_variable::{TypeVariableOrigin, TypeVariableOriginKind};
 use rustc_infer::infer::TyCtxtInferExt;
 use rustc_lint::LateContext;
@@ -32,7 +32,8 @@ use std::assert_matches::debug_assert_matches;
 use std::iter;
 
 use crate::rustc_lint::LintContext;
-use crate::{match_def_path, path_res};
+use crate::visitors::for_each_expr;
+use crate::{def_path_def_ids, is_res_lang_ctor, match_def_path, path_res, paths};
 use rustc_middle::lint::in_external_macro;
 
 mod type_certainty;
@@ -1341,3 +1342,258 @@ pub fn result_err_ty<'tcx>(
         None
     }
 }
+
+/// Returns `E` if the type is written as `Result<T, E>`. The path is matched by what it resolves
+/// to, so renamed imports like `use core::result::Result as StdResult` are included. For any other
+/// type, including aliases of `Result` like `io::Result<T>`, this returns `None`.
+pub fn result_err_hir_ty<'tcx>(cx: &LateContext<'_>, hir_ty: &'tcx hir::Ty<'tcx>) -> Option<&'tcx hir::Ty<'tcx>> {
+    if let TyKind::Path(hir::QPath::Resolved(_, path)) = hir_ty.kind
+        && let Res::Def(DefKind::Enum, def_id) = path.res
+        && cx.tcx.is_diagnostic_item(sym::Result, def_id)
+        && let Some(args) = path.segments.last().and_then(|segment| segment.args)
+        && let [_, hir::GenericArg::Type(err_ty)] = args.args
+    {
+        Some(err_ty)
+    } else {
+        None
+    }
+}
+
+/// Returns the error type of the innermost `Result` in the type, peeling the wrappers that commonly
+/// carry one. These are:
+///
+/// * `Option<T>` and `Poll<T>`, e.g. `Poll<Option<Result<T, E>>>` as returned by
+///   `Stream::poll_next`.
+/// * `Result<T, E>` itself, through its `Ok` type, so `Result<Result<T, E1>, E2>` returns `E1`.
+/// * `Pin<P>` and `Box<T>`, e.g. the `Pin<Box<dyn Future<Output = T>>>` that `#[async_trait]` turns
+///   the return type of `async fn`s into.
+/// * Opaque types with a `Future<Output = T>` or `futures_core::Stream<Item = T>` bound, e.g. the
+///   return type of an `async fn`, and trait objects of these traits.
+///
+/// Peeling stops at the first type that isn't one of these wrappers. The error type of the last
+/// `Result` peeled on the way is returned, or `None` if no `Result` was found. Other types aren't
+/// looked into, e.g. the `Result` in `Vec<Result<T, E>>` is not found.
+pub fn peel_to_result_err<'tcx>(cx: &LateContext<'tcx>, mut ty: Ty<'tcx>) -> Option<Ty<'tcx>> {
+    let mut err_ty = None;
+    loop {
+        ty = match *ty.kind() {
+            ty::Adt(adt, args) if cx.tcx.is_diagnostic_item(sym::Result, adt.did()) => {
+                err_ty = Some(args.type_at(1));
+                args.type_at(0)
+            },
+            ty::Adt(adt, args)
+                if adt.is_box()
+                    || cx.tcx.is_diagnostic_item(sym::Option, adt.did())
+                    || [LangItem::Poll, LangItem::Pin]
+                        .into_iter()
+                        .any(|item| cx.tcx.lang_items().get(item) == Some(adt.did())) =>
+            {
+                args.type_at(0)
+            },
+            _ => match async_output_ty(cx, ty) {
+                Some(output) => output,
+                None => return err_ty,
+            },
+        };
+    }
+}
+
+/// Returns the `Output` of the `Future`, or the `Item` of the `futures_core::Stream`, the opaque
+/// type is bounded by, or the trait object is an instance of.
+fn async_output_ty<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<Ty<'tcx>> {
+    let is_output = |item_def_id: DefId| {
+        let trait_def_id = cx.tcx.parent(item_def_id);
+        match cx.tcx.item_name(item_def_id) {
+            sym::Output => cx.tcx.lang_items().future_trait() == Some(trait_def_id),
+            sym::Item => match_def_path(cx, trait_def_id, &paths::FUTURES_CORE_STREAM),
+            _ => false,
+        }
+    };
+    match *ty.kind() {
+        ty::Alias(ty::Opaque, alias) => cx
+            .tcx
+            .item_bounds(alias.def_id)
+            .iter_instantiated(cx.tcx, alias.args)
+            .find_map(|clause| {
+                let projection = clause.as_projection_clause()?.skip_binder();
+                is_output(projection.projection_ty.def_id)
+                    .then(|| projection.term.ty())
+                    .flatten()
+            }),
+        ty::Dynamic(bounds, ..) => bounds.projection_bounds().find_map(|projection| {
+            let projection = projection.skip_binder();
+            is_output(projection.def_id).then(|| projection.term.ty()).flatten()
+        }),
+        _ => None,
+    }
+}
+
+/// Infers the error type of a function from the `Err(..)` constructor calls and the `?` operators
+/// in its body. This is meant for functions whose declared return type doesn't spell out the
+/// `Result`, e.g. `-> impl IntoResult` or custom result-like wrappers.
+///
+/// This is best-effort. The operands of `?` are only used if there are no `Err(..)` calls, as
+/// their errors may be converted with `From`. Closures and nested items aren't looked at. Returns
+/// `None` if the body contains neither, if they don't agree on a single error type, or if `?` is
+/// used on something that isn't a `Result`.
+pub fn return_error_ty_through_body<'tcx>(cx: &LateContext<'tcx>, body: &hir::Body<'tcx>) -> Option<Ty<'tcx>> {
+    let typeck = cx.tcx.typeck_body(body.id());
+    let mut ctor_err_tys = Vec::new();
+    let mut try_err_tys = Vec::new();
+    let res = for_each_expr(body.value, |e| {
+        let (err_tys, ty) = match e.kind {
+            ExprKind::Call(ctor, [_])
+                if let ExprKind::Path(qpath) = &ctor.kind
+                    && is_res_lang_ctor(cx, typeck.qpath_res(qpath, ctor.hir_id), LangItem::ResultErr) =>
+            {
+                (&mut ctor_err_tys, typeck.expr_ty(e))
+            },
+            // `expr?` is desugared to `match Try::branch(expr) { .. }`
+            ExprKind::Match(scrutinee, _, MatchSource::TryDesugar(_))
+                if let ExprKind::Call(_, [operand]) = scrutinee.kind =>
+            {
+                (&mut try_err_tys, typeck.expr_ty(operand))
+            },
+            _ => return ControlFlow::Continue(()),
+        };
+        if is_type_diagnostic_item(cx, ty, sym::Result)
+            && let ty::Adt(_, args) = ty.kind()
+            && !args.type_at(1).references_error()
+        {
+            err_tys.push(args.type_at(1));
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    });
+    if res.is_some() {
+        return None;
+    }
+    let err_tys = if ctor_err_tys.is_empty() {
+        try_err_tys
+    } else {
+        ctor_err_tys
+    };
+    err_tys.into_iter().all_equal_value().ok()
+}
+
+/// Checks if the type is `Box<dyn Error>`, with any auto traits or lifetime bounds.
+/// `std::error::Error` is a re-export of `core::error::Error`, so this also matches boxed errors
+/// in `no_std` crates.
+pub fn is_boxed_dyn_error(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    // `dyn Error + Send + Sync + 'static` only has `Error` as its principal trait, the auto traits
+    // and the lifetime bound are separate predicates that we don't care about
+    if is_type_lang_item(cx, ty, LangItem::OwnedBox)
+        && let ty::Dynamic(predicates, _, _) = ty.boxed_ty().kind()
+    {
+        predicates
+            .principal_def_id()
+            .is_some_and(|def_id| cx.tcx.is_diagnostic_item(sym::Error, def_id))
+    } else {
+        false
+    }
+}
+
+/// Checks if the type is `Box<dyn Any>`, with any auto traits or lifetime bounds, e.g. the
+/// `Box<dyn Any + Send>` payload `std::panic::catch_unwind` returns.
+pub fn is_boxed_dyn_any(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    if is_type_lang_item(cx, ty, LangItem::OwnedBox)
+        && let ty::Dynamic(predicates, _, _) = ty.boxed_ty().kind()
+    {
+        predicates
+            .principal_def_id()
+            .is_some_and(|def_id| cx.tcx.is_diagnostic_item(sym::Any, def_id))
+    } else {
+        false
+    }
+}
+
+/// Checks if the type implements `std::error::Error`, or `core::error::Error` in `no_std` crates.
+///
+/// This uses the `ParamEnv` of the item being linted, so a type parameter only implements the
+/// trait if the item has an `Error` bound for it, and a generic type like `Wrapper<E>` only if the
+/// bounds of its `Error` impl hold for `E`. Projections are normalized as part of the check, so
+/// `<T as Trait>::Error` implements the trait if the item has a bound for it, or if it normalizes
+/// to a type that does. Types with escaping bound regions never implement it.
+///
+/// Note that `Box<dyn Error>` doesn't implement `Error` itself, see [`is_boxed_dyn_error`] to check
+/// for it.
+pub fn implements_error_trait<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    cx.tcx
+        .get_diagnostic_item(sym::Error)
+        .is_some_and(|error_def_id| implements_trait(cx, ty, error_def_id, &[]))
+}
+
+/// Returns the name of the crate the type is defined in, e.g. `core` for `std::fmt::Error`, which
+/// is re-exported by `std`. Returns `None` for types that aren't ADTs.
+pub fn defining_crate_name(cx: &LateContext<'_>, ty: Ty<'_>) -> Option<Symbol> {
+    if let ty::Adt(adt, _) = ty.kind() {
+        Some(cx.tcx.crate_name(adt.did().krate))
+    } else {
+        None
+    }
+}
+
+/// Checks if the type is an owned or shared string, i.e. `String`, `Box<str>`, `Rc<str>`,
+/// `Arc<str>` or `Cow<str>`.
+pub fn is_string_like_ty(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    if is_type_lang_item(cx, ty, LangItem::String) {
+        return true;
+    }
+    match ty.kind() {
+        ty::Adt(adt, args) if adt.is_box() => args.type_at(0).is_str(),
+        ty::Adt(adt, args) if cx.tcx.is_diagnostic_item(sym::Cow, adt.did()) => args.type_at(1).is_str(),
+        ty::Adt(adt, args)
+            if cx.tcx.is_diagnostic_item(sym::Rc, adt.did()) || cx.tcx.is_diagnostic_item(sym::Arc, adt.did()) =>
+        {
+            args.type_at(0).is_str()
+        },
+        _ => false,
+    }
+}
+
+/// Checks if the type is an unstructured error type, i.e. one that doesn't let users tell
+/// different kinds of errors apart. These are:
+///
+/// * Strings as matched by [`is_string_like_ty`], which are just an error message, and
+///   `Vec<String>`, which is a list of them.
+/// * Tuples of only primitives and strings that contain at least one string, e.g. `(i32, String)`.
+///   Tuples of only integers are error codes, and tuples containing any other type are assumed to
+///   be structured.
+/// * `Box<dyn Error>`, with any auto traits or lifetime bounds, which erases the concrete type of
+///   the error, and `Box<dyn Any>`, which erases everything else about it as well.
+/// * The catch-all error types of error handling crates: `anyhow::Error`, `eyre::Report`,
+///   `failure::Error` and `snafu::Whatever`. Their public paths are resolved to the types they
+///   refer to, so they're recognized independently of the module they are defined in, and under any
+///   name they are re-exported as.
+pub fn is_unstructured_error_ty(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    if is_string_like_ty(cx, ty) || is_boxed_dyn_error(cx, ty) || is_boxed_dyn_any(cx, ty) {
+        return true;
+    }
+    match ty.kind() {
+        ty::Adt(adt, args) if cx.tcx.is_diagnostic_item(sym::Vec, adt.did()) => {
+            is_type_lang_item(cx, args.type_at(0), LangItem::String)
+        },
+        // Resolving the paths is comparatively expensive, so skip it for types that can't match. None of
+        // the types are defined in the local crate, and they are all defined in the crate their path
+        // starts with.
+        ty::Adt(adt, _) if adt.did().is_local() => false,
+        ty::Adt(adt, _) => {
+            let krate = cx.tcx.crate_name(adt.did().krate);
+            [
+                &paths::ANYHOW_ERROR[..],
+                &paths::EYRE_REPORT,
+                &paths::FAILURE_ERROR,
+                &paths::SNAFU_WHATEVER,
+            ]
+            .iter()
+            .filter(|path| path[0] == krate.as_str())
+            .any(|path| def_path_def_ids(cx, path).any(|def_id| def_id == adt.did()))
+        },
+        ty::Tuple(tys) => {
+            tys.iter().any(|ty| is_string_like_ty(cx, ty))
+                && tys.iter().all(|ty| ty.is_primitive() || is_string_like_ty(cx, ty))
+        },
+        _ => false,
+    }
+}
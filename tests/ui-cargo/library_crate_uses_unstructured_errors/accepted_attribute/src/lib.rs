@@ -0,0 +1,19 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+pub struct Plugin;
+
+#[clippy::accepted_unstructured_error]
+pub fn load_plugin() -> anyhow::Result<Plugin> {
+    todo!()
+}
+
+impl Plugin {
+    #[clippy::accepted_unstructured_error]
+    pub fn run(&self) -> Result<(), anyhow::Error> {
+        todo!()
+    }
+}
+
+pub fn unload_plugin(_: Plugin) -> anyhow::Result<()> {
+    todo!()
+}
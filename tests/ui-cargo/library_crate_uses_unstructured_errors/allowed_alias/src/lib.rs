@@ -0,0 +1,29 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+#[allow(clippy::library_crates_structured_errors)]
+pub type MyResult<T> = anyhow::Result<T>;
+
+// The `allow` on the alias covers aliases built on it as well
+pub type Nested<T> = MyResult<T>;
+
+pub type Linted<T> = anyhow::Result<T>;
+
+pub fn uses_alias() -> MyResult<()> {
+    todo!()
+}
+
+pub fn uses_nested() -> Nested<u32> {
+    todo!()
+}
+
+pub fn uses_linted() -> Linted<()> {
+    todo!()
+}
+
+pub struct Client;
+
+impl Client {
+    pub fn connect(&self) -> MyResult<Self> {
+        todo!()
+    }
+}
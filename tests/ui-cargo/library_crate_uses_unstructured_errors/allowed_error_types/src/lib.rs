@@ -0,0 +1,14 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+pub fn uses_allowed_error_directly() -> Result<(), anyhow::Error> {
+    todo!()
+}
+
+pub fn uses_allowed_error_indirectly() -> anyhow::Result<()> {
+    uses_allowed_error_directly()?;
+    Ok(())
+}
+
+pub fn uses_string_error() -> Result<(), String> {
+    todo!()
+}
@@ -0,0 +1,46 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use anyhow::Context;
+use std::collections::HashMap;
+
+pub fn read_config(path: &str) -> anyhow::Result<String> {
+    std::fs::read_to_string(path).context("failed to read the config")
+}
+
+pub fn parse_port(config: &HashMap<String, String>) -> Result<u16, anyhow::Error> {
+    let port = config.get("port").context("missing port")?;
+    port.parse().with_context(|| format!("invalid port `{port}`"))
+}
+
+// No `anyhow::Context`
+pub fn parse_host(config: &HashMap<String, String>) -> anyhow::Result<String> {
+    config
+        .get("host")
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("missing host"))
+}
+
+// Not exported
+fn read_secret(path: &str) -> anyhow::Result<String> {
+    std::fs::read_to_string(path).context("failed to read the secret")
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+// The context is handled internally
+pub fn secret_len(path: &str) -> Result<usize, Error> {
+    Ok(read_secret(path).map(|secret| secret.len()).unwrap_or_default())
+}
@@ -0,0 +1,23 @@
+#![warn(clippy::api_hygiene)]
+
+pub fn parse(input: &str) -> Result<u32, String> {
+    input.parse().map_err(|_| format!("invalid number: {input}"))
+}
+
+pub fn load(path: &str) -> anyhow::Result<String> {
+    Ok(std::fs::read_to_string(path)?)
+}
+
+pub fn report(error: anyhow::Error) {
+    eprintln!("{error}");
+}
+
+pub fn first_word(text: &str) -> &str {
+    text.split_whitespace().next().unwrap()
+}
+
+// Lints of the group can still be allowed individually
+#[allow(clippy::library_panics)]
+pub fn last_word(text: &str) -> &str {
+    text.split_whitespace().last().unwrap()
+}
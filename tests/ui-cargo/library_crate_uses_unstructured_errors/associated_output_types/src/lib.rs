@@ -0,0 +1,60 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::ops::Index;
+
+#[derive(Debug)]
+pub struct LookupError;
+
+pub trait Lookup {
+    type Output;
+
+    fn lookup(&self, key: &str) -> Self::Output;
+}
+
+pub struct Registry;
+
+impl Lookup for Registry {
+    type Output = anyhow::Result<u32>;
+
+    // Reported at the `Output` of the impl
+    fn lookup(&self, _key: &str) -> Self::Output {
+        todo!()
+    }
+}
+
+pub struct Table {
+    cells: Vec<Result<u8, anyhow::Error>>,
+}
+
+impl Index<usize> for Table {
+    type Output = Result<u8, anyhow::Error>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.cells[index]
+    }
+}
+
+pub struct StructuredRegistry;
+
+impl Lookup for StructuredRegistry {
+    type Output = Result<u32, LookupError>;
+
+    fn lookup(&self, _key: &str) -> Self::Output {
+        todo!()
+    }
+}
+
+// Not linted, the type isn't exported
+struct PrivateRegistry;
+
+impl Lookup for PrivateRegistry {
+    type Output = anyhow::Result<u32>;
+
+    fn lookup(&self, _key: &str) -> Self::Output {
+        todo!()
+    }
+}
+
+pub fn private_registry() -> u32 {
+    PrivateRegistry.lookup("").unwrap_or_default()
+}
@@ -0,0 +1,33 @@
+#![warn(clippy::library_crates_structured_errors)]
+#![feature(associated_type_defaults)]
+
+#[derive(Debug)]
+pub struct ServiceError;
+
+pub trait Service {
+    type Error = anyhow::Error;
+}
+
+pub trait StringService {
+    type Error: Into<String>;
+}
+
+pub trait Layer
+where
+    Self::Error: Into<anyhow::Error>,
+{
+    type Error;
+    type Inner: Service<Error = anyhow::Error>;
+}
+
+pub trait Query {
+    type Output = anyhow::Result<u32>;
+}
+
+pub trait StructuredService {
+    type Error: Into<ServiceError> = ServiceError;
+}
+
+trait PrivateService {
+    type Error = anyhow::Error;
+}
@@ -0,0 +1,23 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+pub async fn uses_anyhow() -> anyhow::Result<()> {
+    todo!()
+}
+
+pub async fn uses_eyre() -> eyre::Result<()> {
+    todo!()
+}
+
+pub struct Client;
+
+impl Client {
+    pub async fn request(&self) -> Result<(), anyhow::Error> {
+        todo!()
+    }
+}
+
+pub struct ClientError;
+
+pub async fn structured() -> Result<(), ClientError> {
+    todo!()
+}
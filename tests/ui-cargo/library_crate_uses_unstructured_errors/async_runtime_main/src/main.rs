@@ -0,0 +1,20 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+pub mod server {
+    pub async fn serve() -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    // Blocks on the runtime, but isn't the entry point
+    #[tokio::main(flavor = "current_thread")]
+    pub async fn serve_blocking() -> anyhow::Result<()> {
+        serve().await
+    }
+}
+
+// Not linted, this is still the entry point of the binary
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> anyhow::Result<()> {
+    server::serve().await?;
+    Ok(())
+}
@@ -0,0 +1,41 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use async_trait::async_trait;
+
+#[derive(Debug)]
+pub struct FetchError;
+
+// `#[async_trait]` turns the return types into `Pin<Box<dyn Future<Output = Result<..>> + Send>>`
+#[async_trait]
+pub trait Fetcher {
+    async fn fetch(&self, url: &str) -> anyhow::Result<Vec<u8>>;
+
+    async fn fetch_text(&self, url: &str) -> Result<String, String> {
+        let _ = url;
+        todo!()
+    }
+
+    async fn fetch_structured(&self, url: &str) -> Result<Vec<u8>, FetchError>;
+}
+
+pub struct HttpFetcher;
+
+// Dictated by the trait
+#[async_trait]
+impl Fetcher for HttpFetcher {
+    async fn fetch(&self, _url: &str) -> anyhow::Result<Vec<u8>> {
+        todo!()
+    }
+
+    async fn fetch_structured(&self, _url: &str) -> Result<Vec<u8>, FetchError> {
+        todo!()
+    }
+}
+
+pub struct Client;
+
+impl Client {
+    pub async fn get(&self) -> anyhow::Result<()> {
+        todo!()
+    }
+}
@@ -0,0 +1,12 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+pub mod config {
+    pub fn load() -> anyhow::Result<String> {
+        Ok(String::new())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    config::load()?;
+    Ok(())
+}
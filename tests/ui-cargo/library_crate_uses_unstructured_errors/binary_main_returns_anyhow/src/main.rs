@@ -0,0 +1,17 @@
+// Binaries aren't linted unless `enforce-structured-errors-in-binaries` is enabled, so none of the
+// lints of the group fire
+#![warn(clippy::api_hygiene)]
+
+pub fn parse(input: &str) -> Result<u32, String> {
+    input.parse().map_err(|_| format!("invalid number: {input}"))
+}
+
+pub fn load(path: &str) -> anyhow::Result<String> {
+    Ok(std::fs::read_to_string(path)?)
+}
+
+fn main() -> anyhow::Result<()> {
+    let contents = load("input.txt")?;
+    println!("{:?}", parse(&contents));
+    Ok(())
+}
@@ -0,0 +1,17 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::any::Any;
+use std::panic::{self, UnwindSafe};
+
+pub fn run_plugin<F: FnOnce() + UnwindSafe>(plugin: F) -> Result<(), Box<dyn Any + Send>> {
+    panic::catch_unwind(plugin)
+}
+
+pub fn any_error() -> Result<(), Box<dyn Any>> {
+    todo!()
+}
+
+// Not an error type
+pub fn any_value() -> Box<dyn Any> {
+    Box::new(0)
+}
@@ -0,0 +1,11 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::error::Error;
+
+pub fn send_sync() -> Result<(), Box<dyn Error + Send + Sync>> {
+    todo!()
+}
+
+pub fn send_sync_static() -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+    todo!()
+}
@@ -0,0 +1,41 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+pub struct Client;
+
+#[derive(Default)]
+pub struct ClientBuilder {
+    url: Option<String>,
+}
+
+impl ClientBuilder {
+    pub fn url(mut self, url: String) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    pub fn build(self) -> anyhow::Result<Client> {
+        todo!()
+    }
+
+    pub fn try_build(&self) -> Result<Client, String> {
+        todo!()
+    }
+}
+
+pub struct Encoder;
+
+impl Encoder {
+    pub fn finish(self) -> Result<Vec<u8>, anyhow::Error> {
+        todo!()
+    }
+
+    // Not a builder method
+    pub fn write(&mut self, _bytes: &[u8]) -> anyhow::Result<()> {
+        todo!()
+    }
+}
+
+// Only methods are builder methods
+pub fn build() -> anyhow::Result<Client> {
+    todo!()
+}
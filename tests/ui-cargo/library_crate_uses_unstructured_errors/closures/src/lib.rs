@@ -0,0 +1,36 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+#[derive(Debug)]
+pub struct HandlerError;
+
+pub fn returns_closure() -> impl Fn() -> anyhow::Result<()> {
+    || Ok(())
+}
+
+pub fn takes_callback(_: impl FnOnce(&str) -> Result<(), String>) {}
+
+pub fn takes_boxed_callback(_: Box<dyn FnMut() -> Result<u32, anyhow::Error>>) {}
+
+pub fn takes_boxed_fn(_: Box<dyn Fn() -> anyhow::Result<()>>) {}
+
+pub fn takes_callback_ref(_: &mut dyn FnMut(u32) -> Result<(), Box<dyn std::error::Error>>) {}
+
+pub fn takes_boxed_non_result_callback(_: Box<dyn Fn() -> u32>) {}
+
+pub fn takes_fn_pointer(_: fn() -> Result<(), String>) {}
+
+pub fn takes_generic_callback<F>(_: F)
+where
+    F: Fn(u32) -> anyhow::Result<u32>,
+{
+}
+
+pub fn structured_callback(_: impl Fn() -> Result<(), HandlerError>) {}
+
+fn private_callback(_: impl Fn() -> anyhow::Result<()>) {}
+
+pub fn uses_closures_internally() -> Result<(), HandlerError> {
+    let f = || -> anyhow::Result<()> { Ok(()) };
+    private_callback(f);
+    Ok(())
+}
@@ -0,0 +1,36 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+pub struct Client;
+
+impl Client {
+    pub fn connect(&self) -> anyhow::Result<()> {
+        todo!()
+    }
+
+    pub fn send(&self, _: &[u8]) -> anyhow::Result<usize> {
+        todo!()
+    }
+
+    pub fn receive(&self) -> Result<Vec<u8>, anyhow::Error> {
+        todo!()
+    }
+
+    // The only method using `String` is reported on its own
+    pub fn name(&self) -> Result<String, String> {
+        todo!()
+    }
+
+    #[allow(clippy::library_crates_structured_errors)]
+    pub fn close(&self) -> anyhow::Result<()> {
+        todo!()
+    }
+}
+
+// Free functions are reported one by one
+pub fn connect() -> anyhow::Result<Client> {
+    todo!()
+}
+
+pub fn connect_to(_: &str) -> anyhow::Result<Client> {
+    todo!()
+}
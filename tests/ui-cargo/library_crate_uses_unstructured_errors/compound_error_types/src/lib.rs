@@ -0,0 +1,37 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+#[derive(Debug)]
+pub struct ParseError;
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("parse error")
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Each unstructured part is reported on its own
+pub fn both_opaque() -> Result<(), (anyhow::Error, String)> {
+    todo!()
+}
+
+#[allow(clippy::type_complexity)]
+pub fn nested_tuple() -> Result<(), (ParseError, (usize, Box<dyn std::error::Error>))> {
+    todo!()
+}
+
+pub type ErrorParts = (anyhow::Error, ParseError);
+
+pub fn through_alias() -> Result<(), ErrorParts> {
+    todo!()
+}
+
+// Unstructured as a whole, reported once
+pub fn location_and_message() -> Result<(), (usize, String)> {
+    todo!()
+}
+
+pub fn structured_parts() -> Result<(), (ParseError, usize)> {
+    todo!()
+}
@@ -0,0 +1,37 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::borrow::Cow;
+
+pub fn list_of_messages() -> Result<(), Vec<String>> {
+    todo!()
+}
+
+pub fn cow_message() -> Result<(), Cow<'static, str>> {
+    todo!()
+}
+
+pub fn code_and_message() -> Result<(), (u32, String)> {
+    todo!()
+}
+
+// Without a string, the tuple isn't just an error message
+pub fn codes() -> Result<(), (i32, bool)> {
+    todo!()
+}
+
+pub enum Code {
+    NotFound,
+}
+
+pub fn structured_code_and_message() -> Result<(), (Code, String)> {
+    todo!()
+}
+
+pub fn list_of_codes() -> Result<(), Vec<Code>> {
+    todo!()
+}
+
+// Integer codes are only linted with `flag-integer-error-types`
+pub fn code_pair() -> Result<(), (u32, u32)> {
+    todo!()
+}
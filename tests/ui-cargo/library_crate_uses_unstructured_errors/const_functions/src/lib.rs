@@ -0,0 +1,21 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+pub const fn const_string_error() -> Result<(), String> {
+    Ok(())
+}
+
+pub const fn const_str_error(fail: bool) -> Result<u8, &'static str> {
+    if fail { Err("failed") } else { Ok(0) }
+}
+
+pub struct Parser;
+
+impl Parser {
+    pub const fn parse(&self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+pub const DEFAULT: Result<(), String> = const_string_error();
+
+pub static FALLBACK: Result<u8, &str> = const_str_error(false);
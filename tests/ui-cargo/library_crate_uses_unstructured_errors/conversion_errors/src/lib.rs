@@ -0,0 +1,55 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::num::TryFromIntError;
+
+pub struct Port(pub u16);
+
+impl TryFrom<u32> for Port {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Ok(Self(u16::try_from(value)?))
+    }
+}
+
+impl TryFrom<&str> for Port {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse().map(Self).map_err(|_| format!("invalid port: {value}"))
+    }
+}
+
+pub struct Raw(pub u64);
+
+impl TryInto<Port> for Raw {
+    type Error = Box<dyn std::error::Error>;
+
+    fn try_into(self) -> Result<Port, Self::Error> {
+        Ok(Port(u16::try_from(self.0)?))
+    }
+}
+
+// Structured errors are fine
+impl TryFrom<u64> for Port {
+    type Error = TryFromIntError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        u16::try_from(value).map(Self)
+    }
+}
+
+// So are conversions of types that aren't exported
+struct Private(u16);
+
+impl TryFrom<u32> for Private {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Ok(Self(u16::try_from(value)?))
+    }
+}
+
+pub fn private(value: u32) -> u16 {
+    Private::try_from(value).map_or(0, |private| private.0)
+}
@@ -0,0 +1,10 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+#[derive(Debug)]
+pub struct Error;
+
+pub mod config {
+    pub fn load(_path: &str) -> anyhow::Result<String> {
+        todo!()
+    }
+}
@@ -0,0 +1,6 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+// Not linted, `internal_helpers` doesn't match any of the globs
+pub fn load() -> anyhow::Result<String> {
+    todo!()
+}
@@ -0,0 +1,6 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+// Linted, `mylib_core` matches `mylib-*`
+pub fn load() -> anyhow::Result<String> {
+    todo!()
+}
@@ -0,0 +1,26 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+#[doc(hidden)]
+pub fn __macro_support() -> anyhow::Result<()> {
+    todo!()
+}
+
+#[doc(hidden)]
+pub mod __private {
+    pub fn helper() -> anyhow::Result<()> {
+        todo!()
+    }
+}
+
+pub struct Api;
+
+impl Api {
+    #[doc(hidden)]
+    pub fn __internal(&self) -> anyhow::Result<()> {
+        todo!()
+    }
+
+    pub fn documented(&self) -> anyhow::Result<()> {
+        todo!()
+    }
+}
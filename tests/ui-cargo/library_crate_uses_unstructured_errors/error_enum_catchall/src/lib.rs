@@ -0,0 +1,53 @@
+#![warn(clippy::error_enum_stringly_catchall)]
+#![allow(dead_code)]
+
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(std::io::Error),
+    MissingKey { key: String },
+    Other(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
+        todo!()
+    }
+}
+
+impl Error for ConfigError {}
+
+#[derive(Debug)]
+pub enum ParseError {
+    Custom { msg: Box<str> },
+    Msg(String, usize),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
+        todo!()
+    }
+}
+
+impl Error for ParseError {}
+
+// Not an error type
+pub enum Token {
+    Other(String),
+}
+
+// Not exported
+#[derive(Debug)]
+enum InternalError {
+    Other(String),
+}
+
+impl fmt::Display for InternalError {
+    fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
+        todo!()
+    }
+}
+
+impl Error for InternalError {}
@@ -0,0 +1,36 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::error::Error;
+
+pub struct Connection {
+    pub last_error: Option<anyhow::Error>,
+    pub last_result: Result<usize, Box<dyn Error + Send + Sync>>,
+    // Not public
+    #[allow(dead_code)]
+    pending: Option<anyhow::Error>,
+    // Messages are ordinary data
+    pub last_message: Option<String>,
+}
+
+pub enum State {
+    Connected,
+    Failed(anyhow::Error),
+}
+
+// Not exported
+#[allow(dead_code)]
+struct Retry {
+    pub error: anyhow::Error,
+}
+
+// Error types are checked with `flag-opaque-wrapper-errors`
+#[derive(Debug)]
+pub struct ConnectionError(pub anyhow::Error);
+
+impl std::fmt::Display for ConnectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Error for ConnectionError {}
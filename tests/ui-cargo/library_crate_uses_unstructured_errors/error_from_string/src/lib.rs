@@ -0,0 +1,101 @@
+#![warn(clippy::error_from_string)]
+
+use std::error::Error;
+use std::fmt::{self, Display};
+
+#[derive(Debug)]
+pub enum ConfigError {
+    MissingKey { key: String },
+    Other(String),
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingKey { key } => write!(f, "missing key `{key}`"),
+            Self::Other(message) => f.write_str(message),
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+impl From<String> for ConfigError {
+    fn from(message: String) -> Self {
+        Self::Other(message)
+    }
+}
+
+impl From<&str> for ConfigError {
+    fn from(message: &str) -> Self {
+        Self::Other(message.to_owned())
+    }
+}
+
+impl<'a> From<&'a String> for ConfigError {
+    fn from(message: &'a String) -> Self {
+        Self::Other(message.clone())
+    }
+}
+
+// Not an error type
+#[derive(Debug)]
+pub struct Name(pub String);
+
+impl From<String> for Name {
+    fn from(name: String) -> Self {
+        Self(name)
+    }
+}
+
+// Not exported
+#[derive(Debug)]
+struct PrivateError(String);
+
+impl Display for PrivateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Error for PrivateError {}
+
+impl From<String> for PrivateError {
+    fn from(message: String) -> Self {
+        Self(message)
+    }
+}
+
+pub fn check(key: &str) -> Result<(), Box<dyn Error>> {
+    Err(PrivateError(key.to_owned()).into())
+}
+
+// Generic error types only implement `Error` if the bounds of the impl hold
+#[derive(Debug)]
+pub enum WrappedError<E> {
+    Source(E),
+    Message(String),
+}
+
+impl<E: Display> Display for WrappedError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Source(source) => source.fmt(f),
+            Self::Message(message) => f.write_str(message),
+        }
+    }
+}
+
+impl<E: Error> Error for WrappedError<E> {}
+
+impl<E: Error> From<String> for WrappedError<E> {
+    fn from(message: String) -> Self {
+        Self::Message(message)
+    }
+}
+
+impl<E> From<&str> for WrappedError<E> {
+    fn from(message: &str) -> Self {
+        Self::Message(message.to_owned())
+    }
+}
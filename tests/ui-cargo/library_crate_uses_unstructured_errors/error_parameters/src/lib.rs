@@ -0,0 +1,43 @@
+#![warn(clippy::library_error_parameters)]
+
+use std::error::Error;
+
+pub fn takes_anyhow_error(error: anyhow::Error) {
+    eprintln!("{error}");
+}
+
+pub fn takes_anyhow_error_ref(error: &anyhow::Error) {
+    eprintln!("{error}");
+}
+
+pub fn takes_boxed_error(error: Box<dyn Error>) {
+    eprintln!("{error}");
+}
+
+pub struct Reporter;
+
+impl Reporter {
+    pub fn report(&self, error: Box<dyn Error + Send + Sync>) {
+        eprintln!("{error}");
+    }
+}
+
+// Only the parameter lint is allowed, the return type is still reported
+#[allow(clippy::library_error_parameters)]
+#[warn(clippy::library_crates_structured_errors)]
+pub fn wraps_error(error: Box<dyn Error>) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!("{error}"))
+}
+
+// Strings, trait objects and generic errors are fine
+pub fn takes_message(message: String) {
+    eprintln!("{message}");
+}
+
+pub fn takes_error_ref(error: &dyn Error) {
+    eprintln!("{error}");
+}
+
+pub fn takes_generic_error<E: Error>(error: E) {
+    eprintln!("{error}");
+}
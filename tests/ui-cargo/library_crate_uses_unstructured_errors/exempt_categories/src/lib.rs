@@ -0,0 +1,23 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::error::Error;
+
+// Exempted in `clippy.toml`
+pub fn boxed() -> Result<(), Box<dyn Error>> {
+    todo!()
+}
+
+pub fn boxed_send_sync() -> Result<(), Box<dyn Error + Send + Sync>> {
+    todo!()
+}
+
+pub fn takes_boxed(_error: Box<dyn Error>) {}
+
+// Still reported
+pub fn string() -> Result<(), String> {
+    todo!()
+}
+
+pub fn anyhow() -> anyhow::Result<()> {
+    todo!()
+}
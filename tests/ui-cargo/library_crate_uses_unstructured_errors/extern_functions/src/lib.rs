@@ -0,0 +1,26 @@
+#![warn(clippy::library_crates_structured_errors)]
+// `Result` isn't FFI-safe, which rustc reports as well
+#![allow(improper_ctypes_definitions)]
+
+#[no_mangle]
+pub extern "C" fn init() -> Result<(), anyhow::Error> {
+    todo!()
+}
+
+pub extern "C" fn parse(input: u32) -> Result<u32, String> {
+    Ok(input)
+}
+
+pub struct Handle;
+
+impl Handle {
+    pub extern "C" fn open() -> anyhow::Result<Self> {
+        todo!()
+    }
+}
+
+// Error codes are the usual way to report errors across FFI
+#[no_mangle]
+pub extern "C" fn shutdown() -> i32 {
+    0
+}
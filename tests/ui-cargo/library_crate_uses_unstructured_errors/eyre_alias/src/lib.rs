@@ -0,0 +1,32 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use eyre::Result;
+
+#[allow(clippy::library_crates_structured_errors)]
+pub type MyResult<T> = eyre::Result<T>;
+
+// The `allow` on the alias covers aliases built on it as well
+pub type Nested<T> = MyResult<T>;
+
+pub type Linted<T> = eyre::Result<T>;
+
+pub fn uses_alias() -> MyResult<()> {
+    todo!()
+}
+
+pub fn uses_nested() -> Nested<u32> {
+    todo!()
+}
+
+pub fn uses_linted() -> Linted<()> {
+    todo!()
+}
+
+// `eyre::Result` defaults to `eyre::Result<()>`
+pub fn uses_bare_result() -> eyre::Result {
+    todo!()
+}
+
+pub fn uses_imported_result() -> Result<u32> {
+    todo!()
+}
@@ -0,0 +1,25 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::error::Error;
+
+pub struct Connection;
+
+impl Connection {
+    pub fn close(self) -> anyhow::Result<()> {
+        todo!()
+    }
+
+    pub fn flush(&mut self) -> Result<(), Box<dyn Error>> {
+        todo!()
+    }
+
+    // Not a finalizer
+    pub fn send(&mut self) -> anyhow::Result<()> {
+        todo!()
+    }
+}
+
+// Only methods are finalizers
+pub fn close() -> anyhow::Result<()> {
+    todo!()
+}
@@ -0,0 +1,53 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::fmt;
+
+pub type Error = serde_json::Error;
+
+pub fn parse(input: &str) -> Result<serde_json::Value, serde_json::Error> {
+    serde_json::from_str(input)
+}
+
+pub fn parse_aliased(input: &str) -> Result<serde_json::Value, Error> {
+    serde_json::from_str(input)
+}
+
+// Already reported as an unstructured error type
+pub fn load(input: &str) -> anyhow::Result<serde_json::Value> {
+    Ok(serde_json::from_str(input)?)
+}
+
+// Error types of the crate itself and of the standard library are fine
+#[derive(Debug)]
+pub enum ParseError {
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub fn parse_wrapped(input: &str) -> Result<serde_json::Value, ParseError> {
+    serde_json::from_str(input).map_err(ParseError::Json)
+}
+
+pub fn read(path: &str) -> Result<String, std::io::Error> {
+    std::fs::read_to_string(path)
+}
+
+pub fn number(input: &str) -> Result<u32, std::num::ParseIntError> {
+    input.parse()
+}
+
+// Defined in `core`, re-exported by `std`
+pub fn render(value: &serde_json::Value) -> Result<String, std::fmt::Error> {
+    let mut out = String::new();
+    std::fmt::write(&mut out, format_args!("{value}"))?;
+    Ok(out)
+}
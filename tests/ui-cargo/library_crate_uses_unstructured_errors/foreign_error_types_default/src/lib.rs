@@ -0,0 +1,6 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+// Error types of dependencies are only reported if `flag-foreign-error-types` is enabled
+pub fn parse(input: &str) -> Result<serde_json::Value, serde_json::Error> {
+    serde_json::from_str(input)
+}
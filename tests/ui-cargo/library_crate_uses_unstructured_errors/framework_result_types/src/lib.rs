@@ -0,0 +1,29 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+// Stands in for a web framework, whose handlers have to return its result types
+pub mod web {
+    pub type Error = Box<dyn std::error::Error>;
+    pub type Result<T> = std::result::Result<T, Error>;
+
+    pub enum Response {
+        Ok(String),
+        Err(Box<dyn std::error::Error>),
+    }
+}
+
+pub fn handler() -> web::Result<String> {
+    todo!()
+}
+
+pub async fn async_handler() -> web::Result<String> {
+    todo!()
+}
+
+pub fn response_handler() -> web::Response {
+    todo!()
+}
+
+// Only the configured types are accepted, not the error type they use
+pub fn helper() -> Result<String, web::Error> {
+    todo!()
+}
@@ -0,0 +1,38 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use futures_core::Stream;
+use std::future::{self, Future};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+pub fn fetch() -> impl Future<Output = Result<u32, anyhow::Error>> {
+    future::ready(Err(anyhow::anyhow!("not found")))
+}
+
+pub fn fetch_boxed() -> Pin<Box<dyn Future<Output = Result<u32, String>> + Send>> {
+    Box::pin(future::ready(Err(String::from("not found"))))
+}
+
+struct Lines;
+
+impl Stream for Lines {
+    type Item = Result<String, anyhow::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(None)
+    }
+}
+
+pub fn lines() -> impl Stream<Item = Result<String, anyhow::Error>> {
+    Lines
+}
+
+// Structured errors are fine
+pub fn read() -> impl Future<Output = Result<u32, std::io::Error>> {
+    future::ready(Ok(0))
+}
+
+// Futures taken as arguments aren't part of what the function returns
+pub async fn run(f: impl Future<Output = Result<(), anyhow::Error>>) {
+    let _ = f.await;
+}
@@ -0,0 +1,11 @@
+use std::path::Path;
+use std::{env, fs};
+
+fn main() {
+    let out_dir = env::var_os("OUT_DIR").unwrap();
+    fs::write(
+        Path::new(&out_dir).join("bindings.rs"),
+        "pub fn generated_binding() -> Result<(), Box<dyn std::error::Error>> { Ok(()) }\n",
+    )
+    .unwrap();
+}
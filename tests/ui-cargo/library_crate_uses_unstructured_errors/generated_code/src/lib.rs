@@ -0,0 +1,31 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+// Code generated by the build script isn't linted
+include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+// Neither is code generated by macros
+macro_rules! getter {
+    ($name:ident) => {
+        pub fn $name() -> Result<(), String> {
+            Ok(())
+        }
+    };
+}
+
+getter!(generated_getter);
+
+pub mod nested {
+    macro_rules! generated_impl {
+        ($ty:ident) => {
+            pub struct $ty;
+
+            impl $ty {
+                pub fn get(&self) -> Result<(), Box<dyn std::error::Error>> {
+                    Ok(())
+                }
+            }
+        };
+    }
+
+    generated_impl!(Generated);
+}
@@ -0,0 +1,46 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::error::Error;
+
+pub fn into_anyhow<E: Into<anyhow::Error>>(error: E) -> Result<(), E> {
+    Err(error)
+}
+
+pub fn into_boxed_error<E>(error: E) -> Result<(), E>
+where
+    E: Into<Box<dyn Error + Send + Sync>>,
+{
+    Err(error)
+}
+
+pub fn anyhow_from<E>(error: E) -> Result<(), E>
+where
+    anyhow::Error: From<E>,
+{
+    Err(error)
+}
+
+pub struct Retry;
+
+impl Retry {
+    pub fn run<T, E: Into<anyhow::Error>>(&self, f: impl Fn() -> Result<T, E>) -> Result<T, E> {
+        f()
+    }
+}
+
+// Structured or unconstrained errors are fine
+#[derive(Debug)]
+pub struct ParseError;
+
+pub fn into_structured<E: Into<ParseError>>(error: E) -> Result<(), E> {
+    Err(error)
+}
+
+pub fn any_error<E: Error>(error: E) -> Result<(), E> {
+    Err(error)
+}
+
+// Not the error type
+pub fn greet<T: Into<String>>(name: T) -> Result<String, ParseError> {
+    Ok(format!("hello {}", name.into()))
+}
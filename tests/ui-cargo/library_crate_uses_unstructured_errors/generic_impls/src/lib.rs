@@ -0,0 +1,49 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::fmt::Display;
+
+pub struct Parser<T>(T);
+
+impl<T: Display> Parser<T> {
+    pub fn parse(&self) -> anyhow::Result<T> {
+        todo!()
+    }
+
+    pub fn describe(&self) -> Result<T, String> {
+        todo!()
+    }
+
+    // The error type depends on `T`, so it isn't known to be unstructured
+    pub fn convert<E>(&self) -> Result<T, E> {
+        todo!()
+    }
+}
+
+pub trait Ext {
+    fn ext(&self) -> anyhow::Result<()> {
+        todo!()
+    }
+}
+
+// The signature is dictated by `Ext`, whose default method is linted instead
+impl<T: Display> Ext for T {
+    fn ext(&self) -> anyhow::Result<()> {
+        todo!()
+    }
+}
+
+pub trait Parse {
+    type Error;
+}
+
+// The error type is the associated type of a blanket impl
+impl<T: Display> Parse for T {
+    type Error = Box<dyn std::error::Error>;
+}
+
+impl<T: Display> Parser<T> {
+    pub fn parse_with(&self, value: T) -> Result<(), <T as Parse>::Error> {
+        let _ = value;
+        todo!()
+    }
+}
@@ -0,0 +1,41 @@
+#![warn(clippy::library_crates_structured_errors)]
+// Defaults of function type parameters are a future incompatibility, but still accepted
+#![allow(invalid_type_param_default)]
+
+use std::error::Error;
+
+pub fn anyhow_default<E = anyhow::Error>() -> Result<(), E> {
+    todo!()
+}
+
+pub fn boxed_default<T, E = Box<dyn Error + Send + Sync>>(_value: T) -> Result<T, E> {
+    todo!()
+}
+
+pub struct Client;
+
+impl Client {
+    pub fn call<E = anyhow::Error>(&self) -> Result<(), E> {
+        todo!()
+    }
+}
+
+// Only opaque error types are reported, `String` defaults may be unrelated to errors
+pub fn string_default<T = String>(value: T) -> T {
+    value
+}
+
+#[derive(Debug)]
+pub struct ClientError;
+
+pub fn structured_default<E = ClientError>() -> Result<(), E> {
+    todo!()
+}
+
+fn private<E = anyhow::Error>() -> Result<(), E> {
+    todo!()
+}
+
+pub fn calls_private() -> Result<(), ClientError> {
+    private()
+}
@@ -0,0 +1,25 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::error::Error;
+
+pub fn parse(input: &str) -> anyhow::Result<u32> {
+    Ok(input.parse()?)
+}
+
+pub fn load(path: &str) -> Result<String, Box<dyn Error>> {
+    Ok(std::fs::read_to_string(path)?)
+}
+
+// Only the first use of each error type is pointed out
+pub fn save(path: &str, contents: &str) -> anyhow::Result<()> {
+    std::fs::write(path, contents)?;
+    Ok(())
+}
+
+// Not an opaque error type
+pub fn validate(input: &str) -> Result<(), String> {
+    if input.is_empty() {
+        return Err("empty input".to_string());
+    }
+    Ok(())
+}
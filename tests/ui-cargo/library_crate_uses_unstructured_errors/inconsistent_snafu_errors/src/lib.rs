@@ -0,0 +1,29 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::fmt;
+
+pub fn parse(input: &str) -> Result<u32, snafu::Whatever> {
+    todo!()
+}
+
+pub fn load(path: &str) -> anyhow::Result<String> {
+    todo!()
+}
+
+// Structured error types don't count
+#[derive(Debug)]
+pub enum SaveError {
+    ReadOnly,
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("read-only")
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+pub fn save(path: &str) -> Result<(), SaveError> {
+    todo!()
+}
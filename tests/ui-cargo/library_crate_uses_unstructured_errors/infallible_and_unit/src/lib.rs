@@ -0,0 +1,26 @@
+#![warn(clippy::library_crates_structured_errors)]
+#![allow(clippy::result_unit_err)]
+
+use std::convert::Infallible;
+
+pub fn cannot_fail() -> Result<u32, Infallible> {
+    Ok(0)
+}
+
+pub fn cannot_fail_core() -> Result<u32, core::convert::Infallible> {
+    Ok(0)
+}
+
+pub fn fails_one_way(found: bool) -> Result<u32, ()> {
+    if found { Ok(0) } else { Err(()) }
+}
+
+pub fn nested_unit() -> Option<Result<u32, ()>> {
+    None
+}
+
+pub type NeverFails<T> = Result<T, Infallible>;
+
+pub fn cannot_fail_alias() -> NeverFails<u32> {
+    Ok(0)
+}
@@ -0,0 +1,24 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+pub fn open(_: &str) -> Result<u32, i32> {
+    Err(-1)
+}
+
+pub fn read(_: u32) -> Result<Vec<u8>, u32> {
+    Err(5)
+}
+
+pub type Errno = i32;
+
+pub fn close(_: u32) -> Result<(), Errno> {
+    Err(9)
+}
+
+pub fn stat(_: u32) -> Result<(), (u32, u32)> {
+    Err((2, 0))
+}
+
+// Other primitives aren't error codes
+pub fn check(_: u32) -> Result<(), bool> {
+    Err(false)
+}
@@ -0,0 +1,10 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+// Integer error types are only linted with `flag-integer-error-types`
+pub fn open(_: &str) -> Result<u32, i32> {
+    Err(-1)
+}
+
+pub fn read(_: u32) -> Result<Vec<u8>, u32> {
+    Err(5)
+}
@@ -0,0 +1,17 @@
+#![warn(clippy::library_crates_structured_errors)]
+// Lazy type aliases aren't expanded in function signatures, so they need to be normalized
+#![feature(lazy_type_alias)]
+#![allow(incomplete_features)]
+
+use std::error::Error;
+
+pub type MyBox = Box<dyn Error>;
+pub type Result<T> = std::result::Result<T, MyBox>;
+
+pub fn uses_local_alias() -> Result<()> {
+    todo!()
+}
+
+pub fn uses_local_error_alias() -> std::result::Result<(), MyBox> {
+    todo!()
+}
@@ -0,0 +1,47 @@
+#![warn(clippy::library_crates_structured_errors, clippy::error_type_missing_error_impl)]
+
+use std::fmt;
+
+#[must_use]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ParseError {
+    InvalidNumber,
+    Empty,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid input")
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub fn parse(input: &str) -> Result<u32, ParseError> {
+    input.parse().map_err(|_| ParseError::InvalidNumber)
+}
+
+pub fn parse_boxed(input: &str) -> Result<u32, Box<ParseError>> {
+    parse(input).map_err(Box::new)
+}
+
+pub fn parse_nested(input: &str) -> Option<Result<u32, ParseError>> {
+    (!input.is_empty()).then(|| parse(input))
+}
+
+#[must_use]
+#[derive(Debug)]
+pub struct Message(pub String);
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Message {}
+
+pub fn message() -> Result<(), Message> {
+    todo!()
+}
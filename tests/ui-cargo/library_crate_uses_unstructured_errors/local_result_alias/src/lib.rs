@@ -0,0 +1,14 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::error::Error;
+
+pub type MyBox = Box<dyn Error>;
+pub type Result<T> = std::result::Result<T, MyBox>;
+
+pub fn uses_local_alias() -> Result<()> {
+    todo!()
+}
+
+pub fn uses_local_error_alias() -> std::result::Result<(), MyBox> {
+    todo!()
+}
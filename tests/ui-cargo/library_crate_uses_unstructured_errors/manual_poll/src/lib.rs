@@ -0,0 +1,36 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+pub struct Connection;
+
+impl Connection {
+    pub fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), anyhow::Error>> {
+        todo!()
+    }
+
+    pub fn poll_read(&mut self, _cx: &mut Context<'_>, _buf: &mut [u8]) -> Poll<anyhow::Result<usize>> {
+        todo!()
+    }
+
+    pub fn poll_next(&mut self, _cx: &mut Context<'_>) -> Poll<Option<Result<Vec<u8>, String>>> {
+        todo!()
+    }
+
+    pub fn poll_close(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), std::io::Error>> {
+        todo!()
+    }
+}
+
+pub struct Handshake;
+
+// The signature of `poll` is dictated by `Future`, but its `Output` is chosen by the impl
+impl Future for Handshake {
+    type Output = Result<(), String>;
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
+        todo!()
+    }
+}
@@ -0,0 +1,15 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+pub fn anyhow() -> anyhow::Result<()> {
+    todo!()
+}
+
+macro_rules! boxed_result {
+    () => {
+        Result<(), Box<dyn std::error::Error>>
+    };
+}
+
+pub fn from_macro() -> boxed_result!() {
+    todo!()
+}
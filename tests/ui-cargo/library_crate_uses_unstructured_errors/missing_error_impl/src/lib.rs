@@ -0,0 +1,67 @@
+#![warn(clippy::error_type_missing_error_impl)]
+
+use std::fmt;
+
+#[derive(Debug)]
+pub struct ParseError;
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid input")
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    MissingKey,
+}
+
+pub struct TimeoutError;
+
+// Not named like an error, but used as one
+#[derive(Debug)]
+pub struct Failure;
+
+pub fn run() -> Result<(), Failure> {
+    todo!()
+}
+
+pub mod io {
+    #[derive(Debug)]
+    pub struct ReadError;
+
+    impl std::fmt::Display for ReadError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("read failed")
+        }
+    }
+}
+
+// Implements `Error`
+#[derive(Debug)]
+pub struct WriteError;
+
+impl fmt::Display for WriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("write failed")
+    }
+}
+
+impl std::error::Error for WriteError {}
+
+pub fn write() -> Result<(), WriteError> {
+    todo!()
+}
+
+// Not exported
+#[derive(Debug)]
+struct InternalError;
+
+pub fn check() -> bool {
+    let _ = InternalError;
+    true
+}
+
+// Generic types aren't checked
+#[derive(Debug)]
+pub struct WrappedError<E>(pub E);
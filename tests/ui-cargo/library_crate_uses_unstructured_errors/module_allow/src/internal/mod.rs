@@ -0,0 +1,11 @@
+#![allow(clippy::library_crates_structured_errors)]
+
+pub fn allowed() -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub mod nested {
+    pub fn allowed() -> Result<(), String> {
+        Ok(())
+    }
+}
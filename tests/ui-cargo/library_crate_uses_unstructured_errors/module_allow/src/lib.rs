@@ -0,0 +1,46 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+pub mod internal;
+
+pub mod inline {
+    #![allow(clippy::library_crates_structured_errors)]
+
+    pub fn allowed() -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    pub mod nested {
+        pub fn allowed() -> Result<(), String> {
+            Ok(())
+        }
+
+        pub struct Client;
+
+        impl Client {
+            pub fn allowed(&self) -> Result<(), Box<dyn std::error::Error>> {
+                Ok(())
+            }
+        }
+
+        pub mod reenabled {
+            #![warn(clippy::library_crates_structured_errors)]
+
+            pub fn linted() -> Result<(), String> {
+                Ok(())
+            }
+        }
+    }
+}
+
+pub struct Server;
+
+#[allow(clippy::library_crates_structured_errors)]
+impl Server {
+    pub fn allowed(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn linted() -> Result<(), anyhow::Error> {
+    Ok(())
+}
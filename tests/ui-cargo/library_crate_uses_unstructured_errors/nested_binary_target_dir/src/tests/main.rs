@@ -0,0 +1,8 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+// Only the `tests` directory next to `Cargo.toml` holds integration tests
+pub fn run(_args: &[String]) -> Result<(), String> {
+    todo!()
+}
+
+fn main() {}
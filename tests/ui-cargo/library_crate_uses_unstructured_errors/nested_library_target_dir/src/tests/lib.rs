@@ -0,0 +1,6 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+// Only the `tests` directory next to `Cargo.toml` holds integration tests
+pub fn load(_path: &str) -> Result<String, String> {
+    todo!()
+}
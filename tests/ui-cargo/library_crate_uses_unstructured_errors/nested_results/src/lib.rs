@@ -0,0 +1,42 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+#[derive(Debug)]
+pub struct OuterError;
+
+pub fn nested_in_result() -> Result<Result<(), String>, OuterError> {
+    todo!()
+}
+
+pub fn nested_in_option() -> Option<Result<(), anyhow::Error>> {
+    todo!()
+}
+
+pub fn nested_alias_in_option() -> Option<anyhow::Result<()>> {
+    todo!()
+}
+
+// only the outer error type is reported
+pub fn both_unstructured() -> Result<Result<(), String>, anyhow::Error> {
+    todo!()
+}
+
+pub fn structured_nested() -> Option<Result<(), OuterError>> {
+    todo!()
+}
+
+pub fn deeply_nested() -> Option<Option<Result<(), String>>> {
+    todo!()
+}
+
+pub fn nested_in_ok_option() -> Result<Option<Result<(), String>>, OuterError> {
+    todo!()
+}
+
+pub fn nested_future() -> Option<impl std::future::Future<Output = Result<(), String>>> {
+    None::<std::future::Ready<_>>
+}
+
+// collections of results aren't peeled
+pub fn collected() -> Option<Vec<Result<(), String>>> {
+    todo!()
+}
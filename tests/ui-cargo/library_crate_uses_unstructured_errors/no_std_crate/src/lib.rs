@@ -0,0 +1,21 @@
+#![warn(clippy::library_crates_structured_errors)]
+#![feature(error_in_core)]
+#![no_std]
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::error::Error;
+
+pub fn string_error() -> Result<(), String> {
+    todo!()
+}
+
+pub fn boxed_error() -> Result<(), Box<dyn Error>> {
+    todo!()
+}
+
+pub fn boxed_send_sync_error() -> Result<(), Box<dyn Error + Send + Sync>> {
+    todo!()
+}
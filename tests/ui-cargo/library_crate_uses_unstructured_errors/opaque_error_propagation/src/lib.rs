@@ -0,0 +1,90 @@
+#![warn(clippy::opaque_error_propagation)]
+
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug)]
+pub struct LoadError(String);
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Error for LoadError {}
+
+impl From<anyhow::Error> for LoadError {
+    fn from(error: anyhow::Error) -> Self {
+        Self(error.to_string())
+    }
+}
+
+impl From<Box<dyn Error>> for LoadError {
+    fn from(error: Box<dyn Error>) -> Self {
+        Self(error.to_string())
+    }
+}
+
+impl From<std::io::Error> for LoadError {
+    fn from(error: std::io::Error) -> Self {
+        Self(error.to_string())
+    }
+}
+
+fn read_anyhow() -> anyhow::Result<String> {
+    todo!()
+}
+
+fn read_boxed() -> Result<String, Box<dyn Error>> {
+    todo!()
+}
+
+fn read_io() -> std::io::Result<String> {
+    todo!()
+}
+
+pub fn from_anyhow() -> Result<String, LoadError> {
+    Ok(read_anyhow()?)
+}
+
+pub fn from_boxed() -> Result<String, LoadError> {
+    let config = read_boxed()?;
+    Ok(config)
+}
+
+pub struct Loader;
+
+impl Loader {
+    pub fn load(&self) -> Result<String, LoadError> {
+        Ok(read_anyhow()?)
+    }
+}
+
+// Structured errors are fine
+pub fn from_io() -> Result<String, LoadError> {
+    Ok(read_io()?)
+}
+
+// The return type is reported by `library_crates_structured_errors` instead
+pub fn into_anyhow() -> anyhow::Result<String> {
+    let config = read_boxed().map_err(|e| anyhow::anyhow!("{e}"))?;
+    Ok(config)
+}
+
+// `?` returns from the closure
+pub fn in_closure() -> Result<String, LoadError> {
+    let read = || -> anyhow::Result<String> {
+        let config = read_anyhow()?;
+        Ok(config.trim().to_string())
+    };
+    Ok(read().unwrap_or_default())
+}
+
+fn private() -> Result<String, LoadError> {
+    Ok(read_anyhow()?)
+}
+
+pub fn calls_private() -> Result<String, LoadError> {
+    private()
+}
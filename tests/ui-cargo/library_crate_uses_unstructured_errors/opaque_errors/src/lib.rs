@@ -0,0 +1,33 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::error::Error;
+use std::fmt::{Debug, Display};
+
+#[derive(Debug)]
+pub struct ParseError;
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("parse error")
+    }
+}
+
+impl Error for ParseError {}
+
+pub fn hides_string() -> Result<(), impl Debug> {
+    Err(String::from("oh no"))
+}
+
+pub fn hides_anyhow() -> Result<(), impl Display> {
+    Err(anyhow::anyhow!("oh no"))
+}
+
+pub fn nested_hides_string() -> Option<Result<(), impl Debug>> {
+    Some(Err(String::from("oh no")))
+}
+
+// `anyhow::Error` and `String` don't implement `Error`, so `impl Error` only hides structured
+// errors
+pub fn hides_structured() -> Result<(), impl Error> {
+    Err(ParseError)
+}
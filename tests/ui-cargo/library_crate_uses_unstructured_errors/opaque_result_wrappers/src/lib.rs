@@ -0,0 +1,44 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::num::ParseIntError;
+
+pub trait IntoResult {
+    type Ok;
+    type Err;
+
+    fn into_result(self) -> Result<Self::Ok, Self::Err>;
+}
+
+pub struct Outcome<T, E>(Result<T, E>);
+
+impl<T, E> IntoResult for Outcome<T, E> {
+    type Ok = T;
+    type Err = E;
+
+    fn into_result(self) -> Result<T, E> {
+        self.0
+    }
+}
+
+pub fn string_outcome(input: &str) -> impl IntoResult {
+    if input.is_empty() {
+        return Outcome(Err(String::from("empty input")));
+    }
+    Outcome(Ok(input.len()))
+}
+
+pub fn anyhow_outcome(input: &str) -> impl IntoResult {
+    match parse(input) {
+        Ok(value) => Outcome(Ok(value)),
+        Err(e) => Outcome(Err(anyhow::Error::from(e))),
+    }
+}
+
+fn parse(input: &str) -> Result<u32, ParseIntError> {
+    let value = input.parse()?;
+    Ok(value)
+}
+
+pub fn structured_outcome(input: &str) -> impl IntoResult {
+    Outcome(parse(input))
+}
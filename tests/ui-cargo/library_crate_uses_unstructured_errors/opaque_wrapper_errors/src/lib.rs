@@ -0,0 +1,31 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct Error(#[from] anyhow::Error);
+
+#[derive(Debug, thiserror::Error)]
+pub enum LoadError {
+    #[error(transparent)]
+    Any(#[from] Box<dyn std::error::Error + Send + Sync>),
+}
+
+// Not only a wrapper
+#[derive(Debug, thiserror::Error)]
+#[error("failed to parse line {line}")]
+pub struct ParseError {
+    line: usize,
+    source: anyhow::Error,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum StoreError {
+    #[error("not found")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+pub fn load() -> Result<(), Error> {
+    todo!()
+}
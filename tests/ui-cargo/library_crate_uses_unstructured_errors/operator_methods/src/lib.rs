@@ -0,0 +1,43 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::ops::Add;
+
+#[derive(Clone, Copy)]
+pub struct Meters(pub f64);
+
+// The signature of `add` is dictated by `Add`, but its `Output` is chosen by the impl
+impl Add for Meters {
+    type Output = Result<Meters, anyhow::Error>;
+
+    fn add(self, other: Meters) -> Self::Output {
+        Ok(Meters(self.0 + other.0))
+    }
+}
+
+impl Meters {
+    pub fn checked_add(self, other: Meters) -> Result<Meters, anyhow::Error> {
+        self + other
+    }
+}
+
+pub trait CheckedSub: Sized {
+    type Error;
+
+    fn checked_sub(self, other: Self) -> Result<Self, Self::Error>;
+
+    fn checked_neg(self) -> anyhow::Result<Self>;
+}
+
+// The error type is chosen by the impl of a trait of the crate
+impl CheckedSub for Meters {
+    type Error = anyhow::Error;
+
+    fn checked_sub(self, other: Meters) -> Result<Meters, Self::Error> {
+        Ok(Meters(self.0 - other.0))
+    }
+
+    // Reported at the declaration in the trait
+    fn checked_neg(self) -> anyhow::Result<Meters> {
+        Ok(Meters(-self.0))
+    }
+}
@@ -0,0 +1,29 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+fn private_helper() -> anyhow::Result<()> {
+    todo!()
+}
+
+pub(crate) fn crate_helper() -> anyhow::Result<()> {
+    private_helper()
+}
+
+mod internal {
+    pub fn not_exported() -> anyhow::Result<()> {
+        super::crate_helper()
+    }
+}
+
+pub struct Foo;
+
+pub struct FooError;
+
+impl Foo {
+    fn private_method(&self) -> anyhow::Result<()> {
+        internal::not_exported()
+    }
+
+    pub fn exported_method(&self) -> Result<(), FooError> {
+        self.private_method().map_err(|_| FooError)
+    }
+}
@@ -0,0 +1,43 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::str::FromStr;
+
+pub struct Config;
+
+impl FromStr for Config {
+    type Err = anyhow::Error;
+
+    fn from_str(_: &str) -> Result<Self, Self::Err> {
+        todo!()
+    }
+}
+
+// The associated types are normalized to `anyhow::Error`
+impl TryFrom<&[u8]> for Config {
+    type Error = <Self as FromStr>::Err;
+
+    fn try_from(_: &[u8]) -> Result<Self, Self::Error> {
+        todo!()
+    }
+}
+
+impl Config {
+    pub fn parse(input: &str) -> Result<Self, <Self as FromStr>::Err> {
+        input.parse()
+    }
+
+    pub fn load<E: Into<<Self as FromStr>::Err>>(error: E) -> Result<Self, E> {
+        Err(error)
+    }
+}
+
+pub trait Source {
+    type Error: Into<<Config as FromStr>::Err>;
+}
+
+// Projections that can't be resolved are skipped
+pub trait Loader: FromStr {
+    fn load(input: &str) -> Result<Self, <Self as FromStr>::Err> {
+        input.parse()
+    }
+}
@@ -0,0 +1,19 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+pub use anyhow::Error;
+
+pub mod errors {
+    pub use eyre::Report as ReportError;
+}
+
+pub fn reexported() -> Result<(), Error> {
+    todo!()
+}
+
+pub fn renamed_reexport() -> Result<(), errors::ReportError> {
+    todo!()
+}
+
+pub fn through_use() -> Result<(), crate::Error> {
+    todo!()
+}
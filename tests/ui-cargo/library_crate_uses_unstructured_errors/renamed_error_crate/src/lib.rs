@@ -0,0 +1,13 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+pub mod prelude {
+    pub use errors::Result;
+}
+
+pub fn renamed_dependency() -> errors::Result<()> {
+    todo!()
+}
+
+pub fn reexported_alias() -> prelude::Result<()> {
+    todo!()
+}
@@ -0,0 +1,33 @@
+#![warn(clippy::library_crates_structured_errors)]
+#![feature(error_in_core)]
+#![no_std]
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::error::Error;
+use core::result::Result as StdResult;
+
+pub fn string_error() -> StdResult<(), String> {
+    todo!()
+}
+
+pub fn nested_boxed_error() -> Option<StdResult<(), Box<dyn Error>>> {
+    todo!()
+}
+
+#[derive(Debug)]
+pub struct ParseError;
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("invalid input")
+    }
+}
+
+impl Error for ParseError {}
+
+pub fn structured() -> StdResult<(), ParseError> {
+    todo!()
+}
@@ -0,0 +1,37 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+mod private {
+    pub trait Sealed {}
+}
+
+pub struct File;
+
+impl private::Sealed for File {}
+
+pub trait Storage: private::Sealed {
+    fn read(&self) -> Result<Vec<u8>, String>;
+
+    fn close(self) -> Result<(), String>;
+}
+
+impl Storage for File {
+    fn read(&self) -> Result<Vec<u8>, String> {
+        todo!()
+    }
+
+    fn close(self) -> Result<(), String> {
+        todo!()
+    }
+}
+
+// Not sealed, other crates can implement it
+pub trait Source {
+    fn fetch(&self) -> Result<Vec<u8>, String>;
+}
+
+pub trait Marker {}
+
+// Not sealed, the supertrait is exported
+pub trait Tagged: Marker {
+    fn tag(&self) -> Result<u32, String>;
+}
@@ -0,0 +1,5 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+pub fn string_error() -> Result<(), String> {
+    todo!()
+}
@@ -9,3 +9,24 @@ pub fn foo() -> Result<(), String> {
 pub fn bar() -> Result<(), Box<dyn Error>> {
     todo!()
 }
+
+macro_rules! string_result {
+    () => {
+        Result<(), String>
+    };
+}
+
+pub fn baz() -> string_result!() {
+    todo!()
+}
+
+pub mod nested {
+    pub struct Foo;
+
+    /// The suggestion goes in front of the docs of the `impl` block
+    impl Foo {
+        pub fn foo(&self) -> Result<(), String> {
+            todo!()
+        }
+    }
+}
@@ -0,0 +1,15 @@
+#![warn(clippy::library_string_errors)]
+
+use std::error::Error;
+
+pub fn string_error() -> Result<(), String> {
+    todo!()
+}
+
+pub fn boxed_error() -> Result<(), Box<dyn Error>> {
+    todo!()
+}
+
+pub fn anyhow_error() -> anyhow::Result<()> {
+    todo!()
+}
@@ -0,0 +1,31 @@
+#![warn(clippy::library_crates_structured_errors)]
+#![warn(clippy::library_string_errors)]
+
+use std::borrow::Cow;
+use std::rc::Rc;
+use std::sync::Arc;
+
+pub fn boxed_str() -> Result<(), Box<str>> {
+    Err("oh no".into())
+}
+
+pub fn rc_str() -> Result<(), Rc<str>> {
+    Err("oh no".into())
+}
+
+pub fn arc_str() -> Result<(), Arc<str>> {
+    Err("oh no".into())
+}
+
+pub fn cow_str() -> Result<(), Cow<'static, str>> {
+    Err(Cow::Borrowed("oh no"))
+}
+
+pub fn tuple_with_boxed_str() -> Result<(), (u16, Box<str>)> {
+    Err((404, "not found".into()))
+}
+
+// Boxed slices of anything other than `str` are fine
+pub fn boxed_bytes() -> Result<(), Box<[u8]>> {
+    Err(Box::new([0]))
+}
@@ -0,0 +1,41 @@
+#![warn(clippy::stringified_error_handling, clippy::library_string_errors)]
+
+pub fn formatted(input: &str) -> u16 {
+    input.parse().map_err(|e| format!("{e}")).unwrap_or_else(|message| {
+        eprintln!("{message}");
+        8080
+    })
+}
+
+pub fn to_string(input: &str) -> Option<u16> {
+    input.parse::<u16>().map_err(|e| e.to_string()).ok()
+}
+
+pub struct Config;
+
+impl Config {
+    pub fn is_valid(&self, input: &str) -> bool {
+        input.parse::<u16>().map_err(|e| e.to_string()).is_ok()
+    }
+}
+
+// Propagated strings are reported by `library_string_errors`
+pub fn propagated(input: &str) -> Result<u16, String> {
+    let port = input.parse::<u16>().map_err(|e| e.to_string())?;
+    Ok(port)
+}
+
+pub fn handles_the_error(input: &str) -> u16 {
+    input.parse().unwrap_or_else(|e: std::num::ParseIntError| {
+        eprintln!("{e}");
+        8080
+    })
+}
+
+fn private(input: &str) -> u16 {
+    input.parse().map_err(|e| format!("{e}")).unwrap_or(8080)
+}
+
+pub fn calls_private(input: &str) -> u16 {
+    private(input)
+}
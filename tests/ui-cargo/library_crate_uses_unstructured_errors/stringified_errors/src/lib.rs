@@ -0,0 +1,47 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::num::ParseIntError;
+
+#[derive(Debug)]
+pub struct ConfigError(pub String);
+
+impl From<String> for ConfigError {
+    fn from(message: String) -> Self {
+        Self(message)
+    }
+}
+
+pub fn to_string(input: &str) -> Result<u32, ConfigError> {
+    let value = input.parse::<u32>().map_err(|e| e.to_string())?;
+    Ok(value)
+}
+
+pub fn formatted(input: &str) -> Result<u32, ConfigError> {
+    let value = input
+        .parse::<u32>()
+        .map_err(|e| format!("invalid value `{input}`: {e}"))?;
+    Ok(value)
+}
+
+pub fn in_block(input: &str) -> Result<u32, ConfigError> {
+    let value = input.parse::<u32>().map_err(|e| -> String { e.to_string() })?;
+    Ok(value)
+}
+
+#[derive(Debug)]
+pub enum StructuredError {
+    Parse(ParseIntError),
+}
+
+pub fn structured(input: &str) -> Result<u32, StructuredError> {
+    input.parse::<u32>().map_err(StructuredError::Parse)
+}
+
+fn private(input: &str) -> Result<u32, ConfigError> {
+    let value = input.parse::<u32>().map_err(|e| e.to_string())?;
+    Ok(value)
+}
+
+pub fn calls_private(input: &str) -> Result<u32, ConfigError> {
+    private(input)
+}
@@ -0,0 +1,34 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::error::Error;
+
+pub fn string_error() -> Result<(), String> {
+    todo!()
+}
+
+pub fn string_callback(_: impl Fn() -> Result<(), String>) -> Result<(), String> {
+    todo!()
+}
+
+pub fn boxed_error() -> Result<(), Box<dyn Error>> {
+    todo!()
+}
+
+pub fn anyhow_error() -> anyhow::Result<()> {
+    todo!()
+}
+
+// Not counted, the lint is allowed
+#[allow(clippy::library_crates_structured_errors)]
+pub fn allowed() -> Result<(), String> {
+    todo!()
+}
+
+// Not counted, the function isn't exported
+fn private() -> Result<(), String> {
+    todo!()
+}
+
+pub fn uses_private() {
+    let _ = private();
+}
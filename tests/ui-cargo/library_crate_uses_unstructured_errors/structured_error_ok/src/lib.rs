@@ -0,0 +1,35 @@
+#![warn(clippy::library_crates_structured_errors, clippy::error_enum_stringly_catchall)]
+
+// Callers downcast through `Error::inner`
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+#[clippy::structured_error_ok]
+pub struct Error(#[from] anyhow::Error);
+
+impl Error {
+    pub fn inner(&self) -> &anyhow::Error {
+        &self.0
+    }
+}
+
+// Not marked
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct LoadError(#[from] anyhow::Error);
+
+#[derive(Debug, thiserror::Error)]
+#[clippy::structured_error_ok]
+pub enum ConfigError {
+    #[error("missing key `{0}`")]
+    MissingKey(&'static str),
+    #[error("{0}")]
+    Other(String),
+}
+
+pub fn load() -> Result<(), Error> {
+    todo!()
+}
+
+pub fn load_config() -> Result<(), ConfigError> {
+    todo!()
+}
@@ -0,0 +1,29 @@
+#![warn(
+    clippy::library_crates_structured_errors,
+    clippy::library_error_parameters,
+    clippy::known_unstructured_errors
+)]
+
+#[clippy::structured_error_todo]
+pub fn load() -> anyhow::Result<String> {
+    todo!()
+}
+
+#[clippy::structured_error_todo]
+pub fn report(error: anyhow::Error) -> Result<(), String> {
+    Err(error.to_string())
+}
+
+pub struct Store;
+
+impl Store {
+    #[clippy::structured_error_todo]
+    pub fn get(&self) -> Result<String, Box<dyn std::error::Error>> {
+        todo!()
+    }
+}
+
+// Not marked, still reported as usual
+pub fn save() -> anyhow::Result<()> {
+    todo!()
+}
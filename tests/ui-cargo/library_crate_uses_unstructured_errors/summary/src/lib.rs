@@ -0,0 +1,23 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::error::Error;
+
+pub fn string_error() -> Result<(), String> {
+    todo!()
+}
+
+pub fn string_callback(_: impl Fn() -> Result<(), String>) -> Result<(), String> {
+    todo!()
+}
+
+pub fn boxed_error() -> Result<(), Box<dyn Error>> {
+    todo!()
+}
+
+pub fn anyhow_error() -> anyhow::Result<()> {
+    todo!()
+}
+
+pub fn anyhow_callback(_: impl Fn() -> anyhow::Result<()>) -> Result<(), Box<dyn Error>> {
+    todo!()
+}
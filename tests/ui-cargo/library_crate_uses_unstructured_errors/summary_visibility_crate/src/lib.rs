@@ -0,0 +1,7 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+// Not exported, so the summary doesn't call the functions exported
+#[allow(dead_code)]
+pub(crate) fn string_error() -> Result<(), String> {
+    todo!()
+}
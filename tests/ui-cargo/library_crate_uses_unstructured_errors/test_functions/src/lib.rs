@@ -0,0 +1,23 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+#[derive(Debug)]
+pub struct ParseError;
+
+pub fn parse(_: &str) -> Result<u32, ParseError> {
+    todo!()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    pub fn parse_twice(input: &str) -> anyhow::Result<(u32, u32)> {
+        Ok((parse(input).map_err(|_| anyhow::anyhow!("invalid"))?, 0))
+    }
+
+    #[test]
+    pub fn parses() -> anyhow::Result<()> {
+        parse_twice("1")?;
+        Ok(())
+    }
+}
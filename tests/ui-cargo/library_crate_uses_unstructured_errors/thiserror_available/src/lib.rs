@@ -0,0 +1,19 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+#[derive(Debug, thiserror::Error)]
+pub enum ParseError {
+    #[error("invalid number")]
+    InvalidNumber,
+}
+
+pub fn parse(input: &str) -> Result<u32, ParseError> {
+    input.parse().map_err(|_| ParseError::InvalidNumber)
+}
+
+pub fn load(path: &str) -> Result<String, anyhow::Error> {
+    Ok(std::fs::read_to_string(path)?)
+}
+
+pub fn read(path: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    Ok(std::fs::read(path)?)
+}
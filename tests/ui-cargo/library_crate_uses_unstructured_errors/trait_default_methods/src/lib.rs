@@ -0,0 +1,35 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+pub struct Item;
+
+pub trait Repo {
+    fn get(&self) -> anyhow::Result<Item> {
+        todo!()
+    }
+
+    fn name(&self) -> Result<String, String> {
+        todo!()
+    }
+
+    // Required methods are reported as well, rather than in each impl
+    fn put(&mut self, item: Item) -> anyhow::Result<()>;
+}
+
+pub struct MemoryRepo;
+
+// The signatures are dictated by the trait, which is reported instead
+impl Repo for MemoryRepo {
+    fn get(&self) -> anyhow::Result<Item> {
+        todo!()
+    }
+
+    fn put(&mut self, _item: Item) -> anyhow::Result<()> {
+        todo!()
+    }
+}
+
+trait PrivateRepo {
+    fn get(&self) -> anyhow::Result<Item> {
+        todo!()
+    }
+}
@@ -0,0 +1,27 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::str::FromStr;
+
+pub struct Config;
+
+impl FromStr for Config {
+    type Err = anyhow::Error;
+
+    fn from_str(_: &str) -> Result<Self, Self::Err> {
+        todo!()
+    }
+}
+
+impl TryFrom<u32> for Config {
+    type Error = String;
+
+    fn try_from(_: u32) -> Result<Self, Self::Error> {
+        todo!()
+    }
+}
+
+impl Config {
+    pub fn load() -> anyhow::Result<Self> {
+        todo!()
+    }
+}
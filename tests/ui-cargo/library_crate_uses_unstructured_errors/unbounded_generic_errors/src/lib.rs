@@ -0,0 +1,43 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+use std::fmt::Debug;
+
+pub fn foo<E>() -> Result<(), E> {
+    Ok(())
+}
+
+pub fn static_only<E: 'static>() -> Result<(), E> {
+    Ok(())
+}
+
+pub fn from_io<E: From<std::io::Error>>() -> Result<(), E> {
+    Ok(())
+}
+
+pub fn debug<E>() -> Result<(), E>
+where
+    E: Debug,
+{
+    Ok(())
+}
+
+pub fn converted<E>() -> Result<(), E>
+where
+    String: Into<E>,
+{
+    Ok(())
+}
+
+pub struct Parser<E>(pub Option<E>);
+
+impl<E> Parser<E> {
+    pub fn parse(&self) -> Result<(), E> {
+        Ok(())
+    }
+}
+
+impl<E: std::error::Error> Parser<E> {
+    pub fn parse_bounded(&self) -> Result<(), E> {
+        Ok(())
+    }
+}
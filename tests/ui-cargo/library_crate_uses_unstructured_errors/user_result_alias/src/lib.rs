@@ -0,0 +1,22 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+pub type R<T> = anyhow::Result<T>;
+pub type Outcome<T> = R<T>;
+pub type Message = String;
+
+pub fn uses_alias() -> R<()> {
+    todo!()
+}
+
+pub fn uses_two_aliases() -> Outcome<()> {
+    todo!()
+}
+
+pub fn uses_error_alias() -> Result<(), Message> {
+    todo!()
+}
+
+// Aliases of other crates aren't noted, the error type is named instead
+pub fn uses_anyhow_alias() -> anyhow::Result<()> {
+    todo!()
+}
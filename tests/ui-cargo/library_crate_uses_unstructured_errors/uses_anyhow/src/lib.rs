@@ -8,3 +8,16 @@ pub fn uses_anyhow_error_indirectly() -> anyhow::Result<()> {
     uses_anyhow_error_directly()?;
     Ok(())
 }
+
+pub mod imported {
+    use anyhow::Result;
+
+    pub fn uses_imported_alias() -> Result<()> {
+        todo!()
+    }
+
+    // The error type is already written out, so only the error enum is suggested
+    pub fn uses_explicit_alias() -> Result<(), anyhow::Error> {
+        todo!()
+    }
+}
@@ -0,0 +1,10 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+pub fn uses_color_eyre_report() -> Result<(), color_eyre::Report> {
+    todo!()
+}
+
+pub fn uses_color_eyre_result() -> color_eyre::Result<()> {
+    uses_color_eyre_report()?;
+    Ok(())
+}
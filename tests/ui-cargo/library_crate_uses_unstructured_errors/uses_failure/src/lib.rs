@@ -0,0 +1,10 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+pub fn uses_failure_error_directly() -> Result<(), failure::Error> {
+    todo!()
+}
+
+pub fn uses_failure_error_indirectly() -> failure::Fallible<()> {
+    uses_failure_error_directly()?;
+    Ok(())
+}
@@ -0,0 +1,29 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+pub(crate) fn crate_helper() -> anyhow::Result<()> {
+    internal::visible_in_parent()
+}
+
+mod internal {
+    pub fn reachable_in_crate() -> anyhow::Result<()> {
+        super::crate_helper()
+    }
+
+    fn private_to_module() -> anyhow::Result<()> {
+        todo!()
+    }
+
+    pub(super) fn visible_in_parent() -> anyhow::Result<()> {
+        private_to_module()
+    }
+}
+
+pub struct Foo;
+
+pub struct FooError;
+
+impl Foo {
+    pub fn exported_method(&self) -> Result<(), FooError> {
+        internal::reachable_in_crate().map_err(|_| FooError)
+    }
+}
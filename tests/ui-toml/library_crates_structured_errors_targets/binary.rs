@@ -0,0 +1,8 @@
+#![warn(clippy::library_crates_structured_errors)]
+
+pub fn load(_path: &str) -> Result<String, String> {
+    //~^ ERROR: `String` is an unstructured error type
+    todo!()
+}
+
+fn main() {}
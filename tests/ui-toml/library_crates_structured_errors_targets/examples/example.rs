@@ -0,0 +1,8 @@
+//@rustc-env:CARGO_MANIFEST_DIR=tests/ui-toml/library_crates_structured_errors_targets
+#![warn(clippy::library_crates_structured_errors)]
+
+pub fn load(_path: &str) -> Result<String, String> {
+    todo!()
+}
+
+fn main() {}
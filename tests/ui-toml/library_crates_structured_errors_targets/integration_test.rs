@@ -0,0 +1,6 @@
+//@compile-flags: --test
+#![warn(clippy::library_crates_structured_errors)]
+
+pub fn load(_path: &str) -> Result<String, String> {
+    todo!()
+}
@@ -0,0 +1,13 @@
+//@no-rustfix
+//@revisions: note help
+//@[note] rustc-env:CLIPPY_CONF_DIR=tests/ui-toml/structured_error_message_style/note
+//@[help] rustc-env:CLIPPY_CONF_DIR=tests/ui-toml/structured_error_message_style/help
+#![warn(clippy::library_crates_structured_errors)]
+#![crate_type = "lib"]
+
+// The advice to mark the error enum `#[non_exhaustive]` follows the configured style, the error
+// enum is suggested as a `help` either way
+pub fn boxed() -> Result<(), Box<dyn std::error::Error>> {
+    //~^ ERROR: `Box<dyn Error>` is an unstructured error type
+    Ok(())
+}
@@ -0,0 +1,33 @@
+//@compile-flags: -Z verbose-internals
+#![warn(clippy::library_crates_structured_errors)]
+#![crate_type = "lib"]
+
+pub struct Outcome<T>(T);
+
+pub type Result<T> = Outcome<T>;
+
+// Not a `std::result::Result`, so the lint can't look at it
+pub fn through_alias() -> Result<()> {
+    //~^ NOTE: found no `Result` in the return type, which resolves to `Outcome<()>`
+    Outcome(())
+}
+
+pub async fn async_through_alias() -> Result<()> {
+    //~^ NOTE: found no `Result` in the return type
+    Outcome(())
+}
+
+pub mod renamed {
+    use super::Result as Custom;
+
+    // Still not a `std::result::Result`, even though it isn't written as `Result`
+    pub fn through_renamed_alias() -> Custom<()> {
+        //~^ NOTE: found no `Result` in the return type
+        super::Outcome(())
+    }
+}
+
+pub fn std_result() -> std::result::Result<(), String> {
+    //~^ ERROR: `String` is an unstructured error type
+    Ok(())
+}
@@ -0,0 +1,93 @@
+#![warn(clippy::library_panics)]
+#![crate_type = "lib"]
+
+pub fn panics() {
+    panic!("oh no");
+    //~^ ERROR: this can panic in an exported function of a library crate
+}
+
+pub fn unreachable(x: u32) -> u32 {
+    match x {
+        0 => 1,
+        _ => unreachable!(),
+        //~^ ERROR: this can panic in an exported function of a library crate
+    }
+}
+
+pub fn unwraps(x: Option<u32>, y: &Result<u32, ()>) -> u32 {
+    x.unwrap() + y.as_ref().expect("y should be ok")
+    //~^ ERROR: this can panic in an exported function of a library crate
+    //~| ERROR: this can panic in an exported function of a library crate
+}
+
+pub struct Parser;
+
+impl Parser {
+    pub fn parse(&self, input: &str) -> u32 {
+        input.parse().unwrap()
+        //~^ ERROR: this can panic in an exported function of a library crate
+    }
+
+    fn parse_private(&self, input: &str) -> u32 {
+        input.parse().unwrap()
+    }
+}
+
+pub fn closure() -> impl Fn(Option<u32>) -> u32 {
+    |x| x.unwrap()
+    //~^ ERROR: this can panic in an exported function of a library crate
+}
+
+pub fn unwrap_or(x: Option<u32>) -> u32 {
+    x.unwrap_or(0)
+}
+
+fn private() {
+    panic!();
+}
+
+pub(crate) fn crate_visible(x: Option<u32>) -> u32 {
+    x.unwrap()
+}
+
+mod private_module {
+    pub fn not_exported() {
+        unreachable!();
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    pub fn test_helper(x: Option<u32>) -> u32 {
+        x.unwrap()
+    }
+}
+
+#[doc(hidden)]
+pub fn hidden(x: Option<u32>) -> u32 {
+    x.unwrap()
+}
+
+macro_rules! generate_getter {
+    () => {
+        pub fn generated(x: Option<u32>) -> u32 {
+            x.unwrap()
+        }
+    };
+}
+
+generate_getter!();
+
+mod custom {
+    // Not the `unreachable!` of the standard library, so it doesn't panic
+    macro_rules! unreachable {
+        () => {
+            0
+        };
+    }
+    pub(crate) use unreachable;
+}
+
+pub fn custom_unreachable() -> u32 {
+    custom::unreachable!()
+}
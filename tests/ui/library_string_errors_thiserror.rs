@@ -0,0 +1,18 @@
+//@aux-build:thiserror.rs
+#![warn(clippy::library_crates_structured_errors)]
+#![crate_type = "lib"]
+
+#[derive(Debug, thiserror::Error)]
+pub enum ParseError {
+    #[error("invalid number")]
+    InvalidNumber,
+}
+
+pub fn parse(input: &str) -> Result<u32, ParseError> {
+    input.parse().map_err(|_| ParseError::InvalidNumber)
+}
+
+pub fn load(_path: &str) -> Result<String, String> {
+    //~^ ERROR: `String` is an unstructured error type
+    todo!()
+}
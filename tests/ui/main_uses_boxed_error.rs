@@ -0,0 +1,14 @@
+#![warn(clippy::main_uses_boxed_error)]
+
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
+    //~^ ERROR: `main` returns an unstructured boxed error
+    Ok(())
+}
+
+mod not_the_entrypoint {
+    fn main() -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+#![warn(clippy::main_uses_boxed_error)]
+
+#[derive(Debug)]
+enum AppError {
+    Io(std::io::Error),
+}
+
+fn main() -> Result<(), AppError> {
+    Ok(())
+}